@@ -54,6 +54,10 @@ impl AdifDocument {
     pub fn records(&self) -> &[Record] {
         &self.records
     }
+
+    pub fn records_mut(&mut self) -> &mut [Record] {
+        &mut self.records
+    }
 }
 
 pub trait IntoAdifDocument {
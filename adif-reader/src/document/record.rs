@@ -28,4 +28,21 @@ impl Record {
     pub fn fields(&self) -> &HashMap<String, String> {
         &self.fields
     }
+
+    /// Sets field value, returning the previous one.
+    pub fn set_field<'a, F: Into<FieldName<'a>>>(
+        &mut self,
+        name: F,
+        value: impl Into<String>,
+    ) -> Option<String> {
+        let field_name = name.into();
+        self.fields
+            .insert(field_name.as_str().to_string(), value.into())
+    }
+
+    /// Removes field, returning its value.
+    pub fn remove_field<'a, F: Into<FieldName<'a>>>(&mut self, name: F) -> Option<String> {
+        let field_name = name.into();
+        self.fields.remove(field_name.as_str())
+    }
 }
@@ -1,3 +1,6 @@
+local exchange = require("exchange")
+
+local rx_template = exchange.template("area power?")
 local qso_numbers = {}
 
 local function get_multiplier(number)
@@ -23,11 +26,18 @@ local function qso_key(record)
 end
 
 local function process_qso(record)
-    local pref = string.sub(record.rx_number, 1, 2)
+    local rx, err = rx_template:parse(record.rx_number or "")
+    if not rx then
+        return {
+            multiplier = nil,
+            point = 0,
+            error = string.format("invalid exchange from %s: %s", record.call, err),
+        }
+    end
 
     return {
-        multiplier = get_multiplier(record.rx_number),
-        point = pref == ("13" and 2 or 1) + (record.mode == "CW" and 1 or 0),
+        multiplier = get_multiplier(rx.area.code),
+        point = (rx.area.prefecture == 13 and 2 or 1) + (record.mode == "CW" and 1 or 0),
     }
 end
 
@@ -32,6 +32,7 @@
   #let antenna = entry.antenna
   #let operator = entry.operator
   #let location = entry.location
+  #let remarks = entry.at("remarks", default: "")
 
   #box(width: 100%, height: 100%)[
     // Bureau call
@@ -116,6 +117,10 @@
     #place(top + left, dx: 40mm, dy: 56.5mm)[
       #text(size: 4mm, antenna)
     ]
+    // Remarks
+    #place(top + left, dx: 40mm, dy: 61mm)[
+      #text(size: 4mm, remarks)
+    ]
     // Operator
     #place(top + left, dx: 40mm, dy: 70mm)[
       #text(size: 4mm, operator)
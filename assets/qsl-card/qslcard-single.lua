@@ -1,3 +1,5 @@
+local callsign = require("callsign")
+
 ---@param args table<string,string>
 ---@param entries QslCardEntry[]
 local function generate(args, entries)
@@ -5,7 +7,6 @@ local function generate(args, entries)
     for i, e in ipairs(entries) do
         local bureau_call = e.qso.call
         local call = e.qso.call
-        local call_suffix_index = call:find("/")
         local via = false
         local datetime = e.qso.datetime
         local timezone = "UTC"
@@ -13,8 +14,13 @@ local function generate(args, entries)
         if e.info.card.manager then
             bureau_call = e.info.card.manager
             via = true
-        elseif call_suffix_index ~= nil then
-            bureau_call = bureau_call:sub(1, call_suffix_index - 1)
+        else
+            bureau_call = callsign.base(call)
+        end
+
+        local remarks = ""
+        if e.satellite and e.satellite.name then
+            remarks = "via " .. e.satellite.name
         end
 
         if e.qso.mode ~= "FT8" then
@@ -38,6 +44,7 @@ local function generate(args, entries)
             location = e.info.operation.address or "",
             operator = e.info.operation.operator or "",
             received = e.info.card.received,
+            remarks = remarks,
         })
     end
 
@@ -0,0 +1,23 @@
+---@meta callsign
+
+---@class callsign
+local callsign = {}
+
+--- Extracts the base callsign in uppercase, dropping designators such as `/P` or `KH2/`.
+---@param call string callsign such as `"KH2/JA1XXX/P"`.
+---@return string base
+function callsign.base(call) end
+
+--- Computes the CQ WPX prefix.
+--- Prefix designators without digits get `0` appended, and suffixes such as `/P` do not count.
+---@param call string callsign such as `"JA1ABC/KH2"`.
+---@return string|nil prefix prefix such as `"KH2"`, or nil if the callsign is malformed.
+function callsign.wpx_prefix(call) end
+
+--- Loads callsign database for Super Check Partial, such as `MASTER.SCP`.
+--- Each line has one callsign, and lines starting with `#` are ignored.
+---@param path string path of the callsign list.
+---@return ScpDatabase database
+function callsign.load_scp(path) end
+
+return callsign
@@ -0,0 +1,27 @@
+---@meta
+
+---@class ScpDatabase
+---@operator len: integer
+local ScpDatabase = {}
+
+--- Checks whether the database has the callsign.
+---@param call string callsign such as `"JA1ABC"`.
+---@return boolean
+function ScpDatabase:contains(call) end
+
+--- Finds callsigns containing the pattern.
+--- `?` matches any character, and `*` matches any sequence.
+---@param pattern string pattern such as `"JL1H?S"` or `"*HIS"`.
+---@return string[] calls
+function ScpDatabase:partial(pattern) end
+
+--- Finds other callsigns within the edit distance, nearest first.
+---@param call string callsign such as `"JA1ABX"`.
+---@param max_distance integer|nil maximum edit distance, 1 by default.
+---@return ScpCandidate[] candidates
+function ScpDatabase:near(call, max_distance) end
+
+---@class ScpCandidate
+---@field call string
+---@field distance integer
+local ScpCandidate = {}
@@ -0,0 +1,13 @@
+---@meta exchange
+
+---@class exchange
+local exchange = {}
+
+--- Constructs exchange template from whitespace-separated part names.
+--- Available parts: `report`, `serial`, `area`, `power`, `cq_zone`, `itu_zone`, `text`.
+--- Parts suffixed with `?` are optional.
+---@param spec string template specifier such as `"report area power"`.
+---@return ExchangeTemplate template
+function exchange.template(spec) end
+
+return exchange
@@ -0,0 +1,26 @@
+---@meta
+
+---@class ExchangeTemplate
+local ExchangeTemplate = {}
+
+--- Parses exchange text.
+---@param text string exchange text such as `"59 13M"`.
+---@return ParsedExchange|nil parsed parsed components, or nil if invalid.
+---@return string|nil error error message if invalid.
+function ExchangeTemplate:parse(text) end
+
+---@class ParsedExchange
+---@field report string|nil
+---@field serial integer|nil
+---@field area JarlArea|nil
+---@field power "P"|"L"|"M"|"H"|nil
+---@field cq_zone integer|nil
+---@field itu_zone integer|nil
+---@field text string|nil
+local ParsedExchange = {}
+
+---@class JarlArea
+---@field kind "prefecture"|"subprefecture"|"city"|"gun"|"ward"
+---@field code string
+---@field prefecture integer
+local JarlArea = {}
@@ -2,7 +2,22 @@
 
 ---@class Exchange
 ---@field tx_report string|nil
+---@field tx_report_detail ReportDetail|nil
 ---@field tx_number string|nil
 ---@field rx_report string|nil
+---@field rx_report_detail ReportDetail|nil
 ---@field rx_number string|nil
 local Exchange = {}
+
+---@class ReportDetail
+---@field raw string report as written in the log
+---@field kind "rs"|"rst"|"db"|nil nil if the report cannot be parsed
+---@field readability integer|nil
+---@field strength integer|nil
+---@field tone integer|nil
+---@field suffix "X"|"C"|"K"|"A"|nil
+---@field db integer|nil
+---@field roger boolean|nil
+---@field valid boolean false if the report cannot be parsed or does not match the mode
+---@field error string|nil why the report is not valid
+local ReportDetail = {}
@@ -3,5 +3,7 @@
 ---@class QslCard
 ---@field should_send boolean
 ---@field received boolean
+---@field confirmed_by ("paper"|"lotw"|"eqsl")[]
 ---@field manager string|nil
+---@field message string|nil
 local QslCard = {}
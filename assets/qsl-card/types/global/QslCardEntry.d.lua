@@ -3,5 +3,8 @@
 ---@class QslCardEntry
 ---@field qso Record
 ---@field exchange Exchange
+---@field station Station
+---@field satellite Satellite|nil
+---@field my_references References
 ---@field info QslInfo
 local QslCardEntry = {}
@@ -6,5 +6,33 @@
 ---@field freq number
 ---@field freq_str string
 ---@field mode string
+---@field submode string|nil
+---@field mode_category "phone"|"cw"|"data"|"weak_signal"|"image"|"other"
 ---@field call string
+---@field band_rx string|nil
+---@field freq_rx number|nil
+---@field freq_rx_str string|nil
+---@field propagation PropagationMode|nil
 local Record = {}
+
+---@alias PropagationMode
+---| "AS" # Aircraft scatter
+---| "AUE" # Aurora-E
+---| "AUR" # Aurora
+---| "BS" # Back scatter
+---| "ECH" # EchoLink
+---| "EME" # Earth-Moon-Earth
+---| "ES" # Sporadic E
+---| "F2" # F2 reflection
+---| "FAI" # Field aligned irregularities
+---| "GWAVE" # Ground wave
+---| "INTERNET" # Internet-assisted
+---| "ION" # Ionoscatter
+---| "IRL" # IRLP
+---| "LOS" # Line of sight
+---| "MS" # Meteor scatter
+---| "RPT" # Repeater or transponder
+---| "RS" # Rain scatter
+---| "SAT" # Satellite
+---| "TEP" # Trans-equatorial
+---| "TR" # Tropospheric ducting
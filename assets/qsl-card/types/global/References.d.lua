@@ -0,0 +1,34 @@
+---@meta
+
+---@class References
+---@field pota PotaRef[]
+---@field sota SotaRef|nil
+---@field wwff WwffRef|nil
+---@field iota IotaRef|nil
+local References = {}
+
+---@class PotaRef
+---@field program string
+---@field number integer
+---@field location string|nil
+---@field text string
+local PotaRef = {}
+
+---@class SotaRef
+---@field association string
+---@field region string
+---@field number integer
+---@field text string
+local SotaRef = {}
+
+---@class WwffRef
+---@field program string
+---@field number integer
+---@field text string
+local WwffRef = {}
+
+---@class IotaRef
+---@field continent string
+---@field number integer
+---@field text string
+local IotaRef = {}
@@ -0,0 +1,14 @@
+---@meta
+
+---@class Satellite
+---@field name string|nil
+---@field mode string|nil
+---@field uplink SatelliteLink
+---@field downlink SatelliteLink|nil
+local Satellite = {}
+
+---@class SatelliteLink
+---@field band string
+---@field freq number|nil
+---@field freq_str string|nil
+local SatelliteLink = {}
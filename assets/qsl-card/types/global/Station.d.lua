@@ -0,0 +1,22 @@
+---@meta
+
+---@class Station
+---@field name string|nil
+---@field qth string|nil
+---@field grid string|nil
+---@field vucc_grids string[]
+---@field location Location|nil
+---@field dxcc integer|nil
+---@field country string|nil
+---@field cq_zone integer|nil
+---@field itu_zone integer|nil
+---@field continent string|nil
+---@field state string|nil
+---@field county string|nil
+---@field references References
+local Station = {}
+
+---@class Location
+---@field latitude number degrees, north positive.
+---@field longitude number degrees, east positive.
+local Location = {}
@@ -12,9 +12,15 @@ use itertools::Itertools as _;
 fn main() {
     println!("cargo::rerun-if-changed=build.rs");
     println!("cargo::rerun-if-changed=prefixes.tsv");
+    println!("cargo::rerun-if-changed=cty.csv");
+    println!("cargo::rerun-if-changed=jarl_areas.tsv");
     let out_dir = var("OUT_DIR").expect("OUT_DIR must be set");
 
     let (prefixes, area_names) = construct_values();
+    let mut area_prefix_indices = vec![vec![]; area_names.len()];
+    for (prefix_index, (_, area_index)) in prefixes.iter().enumerate() {
+        area_prefix_indices[*area_index].push(prefix_index);
+    }
     let mut writer = BufWriter::new(
         File::create(Path::new(&out_dir).join("prefixes.rs")).expect("failed to open file"),
     );
@@ -46,6 +52,16 @@ fn main() {
         writeln!(writer, r#"    "{}","#, name.escape_default()).expect("failed to write");
     }
     writeln!(writer, r#"];"#).expect("failed to write");
+
+    writeln!(writer, r#"pub const AREA_PREFIX_INDICES: &[&[usize]] = &["#)
+        .expect("failed to write");
+    for indices in area_prefix_indices {
+        writeln!(writer, r#"    &{indices:?},"#).expect("failed to write");
+    }
+    writeln!(writer, r#"];"#).expect("failed to write");
+
+    write_dxcc(Path::new(&out_dir));
+    write_jarl_areas(Path::new(&out_dir));
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -225,3 +241,247 @@ fn construct_values() -> (Vec<(Prefix, usize)>, Vec<String>) {
         sorted_areas.into_iter().map(|(_, n)| n).collect(),
     )
 }
+
+#[derive(Debug, Clone, Default)]
+struct DxccOverride {
+    cq_zone: Option<u8>,
+    itu_zone: Option<u8>,
+    continent: Option<String>,
+    location: Option<(f64, f64)>,
+}
+
+fn write_dxcc(out_dir: &Path) {
+    let csv = read_to_string("cty.csv").expect("cty.csv must exist");
+    let mut writer =
+        BufWriter::new(File::create(out_dir.join("dxcc.rs")).expect("failed to open file"));
+
+    let mut prefixes = vec![];
+    let mut exact_calls = vec![];
+    writeln!(
+        writer,
+        r#"pub const ENTITIES: &[crate::dxcc::DxccEntity] = &["#
+    )
+    .expect("failed to write");
+    for (index, csv_line) in csv
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .enumerate()
+    {
+        let columns: Vec<_> = csv_line.splitn(10, ',').collect();
+        let [
+            prefix,
+            name,
+            code,
+            continent,
+            cq,
+            itu,
+            lat,
+            lon,
+            _,
+            patterns,
+        ] = columns[..]
+        else {
+            panic!("invalid line: {csv_line}");
+        };
+        let code: u16 = code.parse().expect("DXCC code must be integer");
+        let cq: u8 = cq.parse().expect("CQ zone must be integer");
+        let itu: u8 = itu.parse().expect("ITU zone must be integer");
+        let lat: f64 = lat.parse().expect("latitude must be number");
+        let lon: f64 = lon.parse().expect("longitude must be number");
+        writeln!(
+            writer,
+            r#"    crate::dxcc::DxccEntity {{ code: Some({code}), prefix: std::borrow::Cow::Borrowed("{}"), name: std::borrow::Cow::Borrowed("{}"), continent: {}, cq_zone: {cq}, itu_zone: {itu}, latitude: {lat:?}, longitude: {:?} }},"#,
+            prefix.escape_default(),
+            name.escape_default(),
+            continent_variant(continent),
+            -lon,
+        )
+        .expect("failed to write");
+
+        let patterns = patterns
+            .strip_suffix(';')
+            .unwrap_or_else(|| panic!("prefix list must end with semicolon: {csv_line}"));
+        for token in patterns.split_whitespace() {
+            let (exact, token) = match token.strip_prefix('=') {
+                Some(call) => (true, call),
+                None => (false, token),
+            };
+            let (pattern, overrides) = parse_override(token);
+            if exact {
+                exact_calls.push((pattern, index, overrides));
+            } else {
+                prefixes.push((pattern, index, overrides));
+            }
+        }
+    }
+    writeln!(writer, r#"];"#).expect("failed to write");
+
+    for (const_name, mut mappings) in [("PREFIXES", prefixes), ("EXACT_CALLS", exact_calls)] {
+        mappings.sort_by(|l, r| l.0.cmp(&r.0));
+        if let Some(window) = mappings.windows(2).find(|w| w[0].0 == w[1].0) {
+            panic!("duplicated DXCC pattern found: {}", window[0].0);
+        }
+
+        writeln!(
+            writer,
+            r#"pub(crate) const {const_name}: &[crate::dxcc::DxccMapping] = &["#
+        )
+        .expect("failed to write");
+        for (pattern, entity, overrides) in mappings {
+            let continent = match overrides.continent {
+                Some(c) => format!("Some({})", continent_variant(&c)),
+                None => "None".to_string(),
+            };
+            let location = match overrides.location {
+                Some((lat, lon)) => format!("Some(({lat:?}, {:?}))", -lon),
+                None => "None".to_string(),
+            };
+            writeln!(
+                writer,
+                r#"    crate::dxcc::DxccMapping {{ pattern: std::borrow::Cow::Borrowed("{}"), entity: {entity}, cq_zone: {:?}, itu_zone: {:?}, continent: {continent}, location: {location} }},"#,
+                pattern.escape_default(),
+                overrides.cq_zone,
+                overrides.itu_zone,
+            )
+            .expect("failed to write");
+        }
+        writeln!(writer, r#"];"#).expect("failed to write");
+    }
+}
+
+/// Splits `JD1(27)[90]<24.28/-153.97>{OC}~-10.0~` into the pattern and its overrides.
+fn parse_override(token: &str) -> (String, DxccOverride) {
+    let pattern_end = token.find(['(', '[', '<', '{', '~']).unwrap_or(token.len());
+    let mut overrides = DxccOverride::default();
+    let mut rest = &token[pattern_end..];
+    while let Some(open) = rest.chars().next() {
+        let close = match open {
+            '(' => ')',
+            '[' => ']',
+            '<' => '>',
+            '{' => '}',
+            '~' => '~',
+            _ => panic!("invalid override: {token}"),
+        };
+        let (value, remaining) = rest[1..]
+            .split_once(close)
+            .unwrap_or_else(|| panic!("unclosed override: {token}"));
+        match open {
+            '(' => overrides.cq_zone = Some(value.parse().expect("CQ zone must be integer")),
+            '[' => overrides.itu_zone = Some(value.parse().expect("ITU zone must be integer")),
+            '<' => {
+                let (lat, lon) = value.split_once('/').expect("location must be lat/lon");
+                overrides.location = Some((
+                    lat.parse().expect("latitude must be number"),
+                    lon.parse().expect("longitude must be number"),
+                ));
+            }
+            '{' => overrides.continent = Some(value.to_string()),
+            _ => (),
+        }
+        rest = remaining;
+    }
+
+    (token[..pattern_end].to_string(), overrides)
+}
+
+fn continent_variant(continent: &str) -> &'static str {
+    match continent {
+        "AF" => "crate::dxcc::Continent::Africa",
+        "AN" => "crate::dxcc::Continent::Antarctica",
+        "AS" => "crate::dxcc::Continent::Asia",
+        "EU" => "crate::dxcc::Continent::Europe",
+        "NA" => "crate::dxcc::Continent::NorthAmerica",
+        "OC" => "crate::dxcc::Continent::Oceania",
+        "SA" => "crate::dxcc::Continent::SouthAmerica",
+        _ => panic!("invalid continent: {continent}"),
+    }
+}
+
+fn write_jarl_areas(out_dir: &Path) {
+    let tsv = read_to_string("jarl_areas.tsv").expect("jarl_areas.tsv must exist");
+    let mut writer =
+        BufWriter::new(File::create(out_dir.join("jarl_areas.rs")).expect("failed to open file"));
+
+    let mut entries = vec![];
+    for tsv_line in tsv.lines().filter(|l| !l.trim().is_empty()) {
+        let columns: Vec<_> = tsv_line.split('\t').collect();
+        let [
+            kind,
+            code,
+            name_ja,
+            name,
+            prefecture,
+            valid_from,
+            valid_until,
+            deleted,
+        ] = columns[..]
+        else {
+            panic!("invalid line: {tsv_line}");
+        };
+        let (kind_rank, kind_variant) = match kind {
+            "prefecture" => (0, "Prefecture"),
+            "subprefecture" => (1, "Subprefecture"),
+            "city" => (2, "City"),
+            "gun" => (3, "Gun"),
+            "ward" => (4, "Ward"),
+            _ => panic!("invalid kind: {tsv_line}"),
+        };
+        if code.is_empty() || !code.bytes().all(|c| c.is_ascii_digit()) {
+            panic!("invalid code: {tsv_line}");
+        }
+        let prefecture: usize = prefecture.parse().expect("prefecture must be integer");
+        if !(1..=48).contains(&prefecture) {
+            panic!("invalid prefecture: {tsv_line}");
+        }
+        let deleted = match deleted {
+            "0" => false,
+            "1" => true,
+            _ => panic!("invalid deleted flag: {tsv_line}"),
+        };
+        entries.push((
+            (kind_rank, code.to_string()),
+            format!(
+                r#"    crate::japan::JarlArea {{ kind: crate::japan::JarlAreaKind::{kind_variant}, code: "{code}", name_ja: "{}", name: "{}", prefecture: crate::japan::Prefecture::ALL[{}], valid_from: {}, valid_until: {}, deleted: {deleted} }},"#,
+                name_ja.escape_default(),
+                name.escape_default(),
+                prefecture - 1,
+                date_literal(valid_from),
+                date_literal(valid_until),
+            ),
+        ));
+    }
+    entries.sort_by(|l, r| l.0.cmp(&r.0));
+    if let Some(window) = entries.windows(2).find(|w| w[0].0 == w[1].0) {
+        panic!("duplicated code found: {}", window[0].0.1);
+    }
+
+    writeln!(
+        writer,
+        r#"pub const JARL_AREAS: &[crate::japan::JarlArea] = &["#
+    )
+    .expect("failed to write");
+    for (_, entry) in entries {
+        writeln!(writer, "{entry}").expect("failed to write");
+    }
+    writeln!(writer, r#"];"#).expect("failed to write");
+}
+
+fn date_literal(date: &str) -> String {
+    if date.is_empty() {
+        return "None".to_string();
+    }
+    let valid = date.len() == 10
+        && date.bytes().enumerate().all(|(i, c)| {
+            if i == 4 || i == 7 {
+                c == b'-'
+            } else {
+                c.is_ascii_digit()
+            }
+        });
+    if !valid {
+        panic!("invalid date: {date}");
+    }
+    format!("Some(time::macros::date!({date}))")
+}
@@ -1,6 +1,6 @@
 use std::env::args;
 
-use callfind::grid_locator::GridLocator;
+use callfind::grid_locator::{GridLocator, GridPrecision};
 
 fn main() {
     let lnglats = args().skip(1);
@@ -12,7 +12,7 @@ fn main() {
             continue;
         };
 
-        let gl = match GridLocator::from_lnglat(longitude, latitude) {
+        let gl = match GridLocator::from_lnglat(longitude, latitude, GridPrecision::Sub3) {
             Ok(gl) => gl,
             Err(e) => {
                 eprintln!("{lnglat}: {e}");
@@ -1,6 +1,6 @@
 use std::env::args;
 
-use callfind::callsign::lookup_prefix_area;
+use callfind::{callsign::lookup_prefix_area, dxcc::lookup_dxcc};
 
 fn main() {
     let calls = args().skip(1);
@@ -11,5 +11,15 @@ fn main() {
 
         call.make_ascii_uppercase();
         println!("{}: {:?}", call, lookup_prefix_area(call.as_bytes()));
+        if let Some(dxcc) = lookup_dxcc(&call) {
+            println!(
+                "  DXCC {} {} ({}, CQ {}, ITU {})",
+                dxcc.code.map_or("-".to_string(), |c| c.to_string()),
+                dxcc.name,
+                dxcc.continent,
+                dxcc.cq_zone,
+                dxcc.itu_zone
+            );
+        }
     }
 }
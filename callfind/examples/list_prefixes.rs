@@ -0,0 +1,26 @@
+use std::env::args;
+
+use callfind::callsign::{Prefix, allocations, area_prefixes, prefix_owners};
+
+fn main() {
+    let queries: Vec<_> = args().skip(1).collect();
+    if queries.is_empty() {
+        for (prefix, assign) in allocations() {
+            println!("{prefix}\t{assign:?}");
+        }
+        return;
+    }
+
+    for query in queries {
+        if let Ok(prefix) = query.parse::<Prefix>() {
+            println!("{prefix}: {}", prefix_owners(&prefix).join(", "));
+            continue;
+        }
+
+        let prefixes: Vec<_> = area_prefixes(&query)
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        println!("{query}: {}", prefixes.join(", "));
+    }
+}
@@ -1,7 +1,15 @@
 mod data;
+mod parsed;
 mod prefix;
+mod validate;
 
-use crate::callsign::data::{AREA_NAMES, PREFIXES, UNASSIGNED_PREFIXES};
+use crate::callsign::data::{AREA_NAMES, AREA_PREFIX_INDICES, PREFIXES, UNASSIGNED_PREFIXES};
+
+pub use crate::callsign::{
+    parsed::{Callsign, CallsignError, CallsignSuffix},
+    prefix::{InvalidPrefix, Prefix},
+    validate::{CallsignIssue, CallsignValidation, StationType, validate_callsign},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CallsignAssign {
@@ -10,6 +18,29 @@ pub enum CallsignAssign {
     Unavailable,
 }
 
+/// Extracts the base callsign in uppercase, dropping portable designators
+/// such as `/P`, `/QRP`, `/1` or `W1/` so that the same operator matches.
+pub fn base_callsign(callsign: &str) -> String {
+    match callsign.parse::<Callsign>() {
+        Ok(parsed) => parsed.base().to_string(),
+        Err(_) => callsign.trim().to_ascii_uppercase(),
+    }
+}
+
+/// Computes the CQ WPX prefix, or `None` if the callsign is malformed.
+/// A callsign without any digit, such as `RAEM`, counts as its first two letters followed by `0`.
+pub fn wpx_prefix(callsign: &str) -> Option<String> {
+    match callsign.parse::<Callsign>() {
+        Ok(parsed) => Some(parsed.wpx_prefix()),
+        Err(CallsignError::NoBaseCallsign) => {
+            let upper = callsign.trim().to_ascii_uppercase();
+            let letters = upper.len() >= 2 && upper.bytes().all(|c| c.is_ascii_uppercase());
+            letters.then(|| format!("{}0", &upper[..2]))
+        }
+        Err(_) => None,
+    }
+}
+
 pub fn lookup_prefix_area(ascii_callsign: &[u8]) -> CallsignAssign {
     if ascii_callsign.len() < 3 {
         return CallsignAssign::Unavailable;
@@ -34,3 +65,135 @@ pub fn lookup_prefix_area(ascii_callsign: &[u8]) -> CallsignAssign {
         }
     }
 }
+
+/// Number of leading characters of the allocated prefix, such as 2 for `A6` in `A61AB`,
+/// or `None` if the callsign is not in any allocation.
+pub(crate) fn allocated_prefix_len(ascii_callsign: &[u8]) -> Option<usize> {
+    let [c1, c2, c3, ..] = *ascii_callsign else {
+        return None;
+    };
+    let i = PREFIXES
+        .binary_search_by(|probe| probe.0.range_cmp(c1, c2, c3))
+        .ok()?;
+    match PREFIXES[i].0 {
+        prefix if !prefix.is_match(c1, c2, c3) => None,
+        Prefix::OneAll(_) => Some(1),
+        Prefix::TwoRange(..) | Prefix::TwoSpecified(..) => Some(2),
+        Prefix::ThreeRange(..) => Some(3),
+    }
+}
+
+/// Levenshtein distance of ASCII strings.
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.as_bytes();
+    let mut row: Vec<_> = (0..=right.len()).collect();
+    for (i, l) in left.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, r) in right.iter().enumerate() {
+            let substitution = diagonal + usize::from(l != *r);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[right.len()]
+}
+
+/// Names of all areas which prefixes are allocated to.
+pub fn area_names() -> &'static [&'static str] {
+    AREA_NAMES
+}
+
+/// Prefix ranges allocated to the area, such as `JA-JS` for Japan.
+/// The name is compared case-insensitively.
+pub fn area_prefixes(area_name: &str) -> Vec<Prefix> {
+    let Some(area_index) = AREA_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(area_name))
+    else {
+        return vec![];
+    };
+    AREA_PREFIX_INDICES[area_index]
+        .iter()
+        .map(|&i| PREFIXES[i].0)
+        .collect()
+}
+
+/// Names of areas which own any part of the prefix range.
+pub fn prefix_owners(prefix: &Prefix) -> Vec<&'static str> {
+    let mut owners = vec![];
+    for (_, area_index) in PREFIXES.iter().filter(|(p, _)| p.overlaps(prefix)) {
+        if !owners.contains(&AREA_NAMES[*area_index]) {
+            owners.push(AREA_NAMES[*area_index]);
+        }
+    }
+    owners
+}
+
+/// Whole allocation table including unassigned blocks, ordered by the first callsign of each prefix.
+pub fn allocations() -> impl Iterator<Item = (Prefix, CallsignAssign)> {
+    let assigned = PREFIXES
+        .iter()
+        .map(|(p, i)| (*p, CallsignAssign::Assigned(AREA_NAMES[*i])));
+    let unassigned = UNASSIGNED_PREFIXES
+        .iter()
+        .map(|p| (*p, CallsignAssign::Unassigned));
+    let mut all: Vec<_> = assigned.chain(unassigned).collect();
+    all.sort_by_key(|(p, _)| {
+        let (p1, (p2, _), (p3, _)) = p.ranges();
+        (p1, p2, p3)
+    });
+    all.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CallsignAssign, Prefix, allocations, area_prefixes, base_callsign, edit_distance,
+        prefix_owners, wpx_prefix,
+    };
+
+    #[test]
+    fn extracts_base_callsign() {
+        assert_eq!(base_callsign("ja1zzz"), "JA1ZZZ");
+        assert_eq!(base_callsign("JA1ZZZ/1"), "JA1ZZZ");
+        assert_eq!(base_callsign("JA1ZZZ/P"), "JA1ZZZ");
+        assert_eq!(base_callsign("W1/JA1ZZZ/QRP"), "JA1ZZZ");
+        assert_eq!(base_callsign("KH6/K1AB"), "K1AB");
+    }
+
+    #[test]
+    fn computes_wpx_prefix() {
+        assert_eq!(wpx_prefix("ja1abc/kh2").as_deref(), Some("KH2"));
+        assert_eq!(wpx_prefix("RAEM").as_deref(), Some("RA0"));
+        assert_eq!(wpx_prefix("JA1 ABC"), None);
+    }
+
+    #[test]
+    fn looks_up_reverse() {
+        let japan: Vec<_> = area_prefixes("japan")
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert!(japan.contains(&"JA-JS".to_string()));
+        assert!(japan.contains(&"8J-8N".to_string()));
+
+        let special: Prefix = "8J-8N".parse().expect("valid prefix");
+        assert_eq!(prefix_owners(&special), vec!["Japan"]);
+        assert!(area_prefixes("Atlantis").is_empty());
+    }
+
+    #[test]
+    fn lists_allocations() {
+        let all: Vec<_> = allocations().collect();
+        assert!(all.iter().any(|(_, a)| *a == CallsignAssign::Unassigned));
+        assert!(all.windows(2).all(|w| !w[0].0.overlaps(&w[1].0)));
+    }
+
+    #[test]
+    fn measures_edit_distance() {
+        assert_eq!(edit_distance("JA1CCC", "JA1CCX"), 1);
+        assert_eq!(edit_distance("JA1CCC", "JA1CC"), 1);
+        assert_eq!(edit_distance("JA1CCC", "7K1ZZZ"), 5);
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+use crate::callsign::{CallsignAssign, lookup_prefix_area};
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum CallsignError {
+    #[error("empty callsign")]
+    Empty,
+
+    #[error("invalid character in callsign")]
+    InvalidCharacter,
+
+    #[error("empty part between slashes")]
+    EmptyPart,
+
+    #[error("no base callsign found")]
+    NoBaseCallsign,
+
+    #[error("ambiguous designator: {0}")]
+    Ambiguous(String),
+}
+
+/// Operating condition designator following the base callsign.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CallsignSuffix {
+    /// `/P`
+    Portable,
+
+    /// `/M`
+    Mobile,
+
+    /// `/MM`
+    MaritimeMobile,
+
+    /// `/AM`
+    AeronauticalMobile,
+
+    /// `/QRP`
+    Qrp,
+
+    /// Any other designator such as `/A`.
+    Other(String),
+}
+
+/// Callsign with optional designators, such as `KH2/JA1XXX/P` or `JA1XXX/3`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Callsign {
+    base: String,
+    prefix_override: Option<String>,
+    prefix_leading: bool,
+    area_override: Option<u8>,
+    suffixes: Vec<CallsignSuffix>,
+}
+
+impl Callsign {
+    /// Callsign without any designators, such as `JA1XXX`.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Prefix given by reciprocal operation, such as `KH2` in `KH2/JA1XXX` or `JA1` in `W1AW/JA1`.
+    pub fn prefix_override(&self) -> Option<&str> {
+        self.prefix_override.as_deref()
+    }
+
+    /// Area number given by `/1` form.
+    pub fn area_override(&self) -> Option<u8> {
+        self.area_override
+    }
+
+    pub fn suffixes(&self) -> &[CallsignSuffix] {
+        &self.suffixes
+    }
+
+    pub fn has_suffix(&self, suffix: &CallsignSuffix) -> bool {
+        self.suffixes.contains(suffix)
+    }
+
+    /// Prefix of the base callsign through its first run of digits after the leading character,
+    /// such as `JA1` in `JA1XXX`, `2E0` in `2E0ABC` or `YT2000` in `YT2000A`.
+    pub fn base_prefix(&self) -> &str {
+        let bytes = self.base.as_bytes();
+        let Some(start) = bytes.iter().skip(1).position(u8::is_ascii_digit) else {
+            return &self.base;
+        };
+        let digits = bytes[start + 1..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        &self.base[..start + 1 + digits]
+    }
+
+    /// Prefix which determines the allocation of this operation.
+    pub fn effective_prefix(&self) -> String {
+        if let Some(prefix) = &self.prefix_override {
+            return prefix.clone();
+        }
+        let base_prefix = self.base_prefix();
+        match self.area_override {
+            Some(area) => format!(
+                "{}{area}",
+                base_prefix.trim_end_matches(|c: char| c.is_ascii_digit())
+            ),
+            None => base_prefix.to_string(),
+        }
+    }
+
+    /// Prefix counted in CQ WPX contests.
+    /// A prefix override without digits gets `0` appended, such as `PA0` for `PA/N8BJQ`,
+    /// and operating condition suffixes such as `/P` and `/MM` do not count.
+    pub fn wpx_prefix(&self) -> String {
+        match &self.prefix_override {
+            Some(prefix) if !prefix.bytes().any(|c| c.is_ascii_digit()) => format!("{prefix}0"),
+            Some(prefix) => {
+                let end = prefix
+                    .rfind(|c: char| c.is_ascii_digit())
+                    .expect("must contain digit");
+                prefix[..=end].to_string()
+            }
+            None => self.effective_prefix(),
+        }
+    }
+
+    /// Looks up allocation by the effective prefix.
+    pub fn lookup_area(&self) -> CallsignAssign {
+        let mut key = self.lookup_key();
+        while key.len() < 3 {
+            key.push('A');
+        }
+        lookup_prefix_area(key.as_bytes())
+    }
+
+    /// Text to look up allocation with: the prefix override,
+    /// or the base callsign with its area number replaced.
+    pub(crate) fn lookup_key(&self) -> String {
+        match (&self.prefix_override, self.area_override) {
+            (Some(prefix), _) => prefix.clone(),
+            (None, Some(_)) => {
+                let prefix_len = self.base_prefix().len();
+                format!("{}{}", self.effective_prefix(), &self.base[prefix_len..])
+            }
+            (None, None) => self.base.clone(),
+        }
+    }
+
+    /// Whether the part has the shape of a complete callsign:
+    /// prefix, digit and suffix letters.
+    fn is_complete(part: &str) -> bool {
+        let bytes = part.as_bytes();
+        (3..=10).contains(&bytes.len())
+            && bytes.iter().any(|c| c.is_ascii_digit())
+            && bytes.last().is_some_and(|c| c.is_ascii_alphabetic())
+    }
+}
+
+impl FromStr for Callsign {
+    type Err = CallsignError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.trim().to_ascii_uppercase();
+        if upper.is_empty() {
+            return Err(CallsignError::Empty);
+        }
+        if !upper
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'/')
+        {
+            return Err(CallsignError::InvalidCharacter);
+        }
+        let parts: Vec<_> = upper.split('/').collect();
+        if parts.iter().any(|p| p.is_empty()) {
+            return Err(CallsignError::EmptyPart);
+        }
+
+        // longest complete part is the base, preferring the former on ties
+        let base_index = parts
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| Callsign::is_complete(p))
+            .fold(
+                None,
+                |longest: Option<(usize, &&str)>, (i, p)| match longest {
+                    Some((_, l)) if l.len() >= p.len() => longest,
+                    _ => Some((i, p)),
+                },
+            )
+            .map(|(i, _)| i)
+            .ok_or(CallsignError::NoBaseCallsign)?;
+
+        let mut callsign = Callsign {
+            base: parts[base_index].to_string(),
+            prefix_override: None,
+            prefix_leading: false,
+            area_override: None,
+            suffixes: vec![],
+        };
+        for (i, part) in parts.iter().enumerate() {
+            let ambiguous = || CallsignError::Ambiguous(part.to_string());
+            if i < base_index {
+                if callsign.prefix_override.is_some() {
+                    return Err(ambiguous());
+                }
+                callsign.prefix_override = Some(part.to_string());
+                callsign.prefix_leading = true;
+                continue;
+            } else if i == base_index {
+                continue;
+            }
+
+            let suffix = match *part {
+                "P" => CallsignSuffix::Portable,
+                "M" => CallsignSuffix::Mobile,
+                "MM" => CallsignSuffix::MaritimeMobile,
+                "AM" => CallsignSuffix::AeronauticalMobile,
+                "QRP" => CallsignSuffix::Qrp,
+                digit if digit.len() == 1 && digit.as_bytes()[0].is_ascii_digit() => {
+                    if callsign.area_override.is_some() {
+                        return Err(ambiguous());
+                    }
+                    callsign.area_override = Some(digit.as_bytes()[0] - b'0');
+                    continue;
+                }
+                other if other.len() == 1 => CallsignSuffix::Other(other.to_string()),
+                prefix => {
+                    if callsign.prefix_override.is_some() {
+                        return Err(ambiguous());
+                    }
+                    callsign.prefix_override = Some(prefix.to_string());
+                    continue;
+                }
+            };
+            callsign.suffixes.push(suffix);
+        }
+
+        Ok(callsign)
+    }
+}
+
+impl Display for CallsignSuffix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CallsignSuffix::Portable => write!(f, "P"),
+            CallsignSuffix::Mobile => write!(f, "M"),
+            CallsignSuffix::MaritimeMobile => write!(f, "MM"),
+            CallsignSuffix::AeronauticalMobile => write!(f, "AM"),
+            CallsignSuffix::Qrp => write!(f, "QRP"),
+            CallsignSuffix::Other(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl Display for Callsign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (&self.prefix_override, self.prefix_leading) {
+            (Some(prefix), true) => write!(f, "{prefix}/{}", self.base)?,
+            (Some(prefix), false) => write!(f, "{}/{prefix}", self.base)?,
+            (None, _) => write!(f, "{}", self.base)?,
+        }
+        if let Some(area) = self.area_override {
+            write!(f, "/{area}")?;
+        }
+        for suffix in &self.suffixes {
+            write!(f, "/{suffix}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Callsign, CallsignError, CallsignSuffix};
+    use crate::callsign::CallsignAssign;
+
+    #[test]
+    fn parses_designators() {
+        let call: Callsign = "kh2/ja1xxx/p".parse().expect("valid callsign");
+        assert_eq!(call.base(), "JA1XXX");
+        assert_eq!(call.prefix_override(), Some("KH2"));
+        assert_eq!(call.suffixes(), &[CallsignSuffix::Portable]);
+        assert_eq!(call.effective_prefix(), "KH2");
+        assert_eq!(call.to_string(), "KH2/JA1XXX/P");
+
+        let call: Callsign = "W1AW/JA1".parse().expect("valid callsign");
+        assert_eq!(call.base(), "W1AW");
+        assert_eq!(call.effective_prefix(), "JA1");
+        assert_eq!(call.to_string(), "W1AW/JA1");
+
+        let call: Callsign = "JA1XXX/3/QRP".parse().expect("valid callsign");
+        assert_eq!(call.area_override(), Some(3));
+        assert!(call.has_suffix(&CallsignSuffix::Qrp));
+        assert_eq!(call.effective_prefix(), "JA3");
+        assert_eq!(call.to_string(), "JA1XXX/3/QRP");
+
+        let call: Callsign = "7K4ABC/MM".parse().expect("valid callsign");
+        assert_eq!(call.base_prefix(), "7K4");
+        assert!(call.has_suffix(&CallsignSuffix::MaritimeMobile));
+    }
+
+    #[test]
+    fn rejects_malformed_callsigns() {
+        assert_eq!("".parse::<Callsign>(), Err(CallsignError::Empty));
+        assert_eq!(
+            "JA1 XXX".parse::<Callsign>(),
+            Err(CallsignError::InvalidCharacter)
+        );
+        assert_eq!(
+            "JA1XXX//P".parse::<Callsign>(),
+            Err(CallsignError::EmptyPart)
+        );
+        assert_eq!(
+            "KH2/P".parse::<Callsign>(),
+            Err(CallsignError::NoBaseCallsign)
+        );
+        assert!(matches!(
+            "JA1XXX/1/2".parse::<Callsign>(),
+            Err(CallsignError::Ambiguous(_))
+        ));
+    }
+
+    #[test]
+    fn looks_up_effective_area() {
+        let call: Callsign = "KH2/JA1XXX".parse().expect("valid callsign");
+        assert_ne!(
+            call.lookup_area(),
+            "JA1XXX".parse::<Callsign>().expect("valid").lookup_area()
+        );
+        let call: Callsign = "JA1XXX/3".parse().expect("valid callsign");
+        assert!(matches!(call.lookup_area(), CallsignAssign::Assigned(_)));
+    }
+
+    #[test]
+    fn computes_wpx_prefix() {
+        let wpx = |c: &str| c.parse::<Callsign>().expect("valid callsign").wpx_prefix();
+        assert_eq!(wpx("N8BJQ"), "N8");
+        assert_eq!(wpx("WB2ABC"), "WB2");
+        assert_eq!(wpx("2E0ABC"), "2E0");
+        assert_eq!(wpx("9A1A"), "9A1");
+        assert_eq!(wpx("YT2000A"), "YT2000");
+        assert_eq!(wpx("JA1ABC/KH2"), "KH2");
+        assert_eq!(wpx("KH6/N8BJQ"), "KH6");
+        assert_eq!(wpx("PA/N8BJQ"), "PA0");
+        assert_eq!(wpx("N8BJQ/VE"), "VE0");
+        assert_eq!(wpx("W1AW/6"), "W6");
+        assert_eq!(wpx("JA1ABC/P"), "JA1");
+        assert_eq!(wpx("JA1ABC/MM"), "JA1");
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let call: Callsign = "JA1XXX/P".parse().expect("valid callsign");
+        assert_eq!(
+            serde_json::to_string(&call).expect("serializable"),
+            r#""JA1XXX/P""#
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
 };
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error as ThisError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Prefix {
@@ -13,6 +15,13 @@ pub enum Prefix {
     ThreeRange(u8, u8, (u8, u8)),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+#[error("invalid prefix: {0}")]
+pub struct InvalidPrefix(pub String);
+
+/// Whole range of a callsign character.
+const FULL_RANGE: (u8, u8) = (b'0', b'Z');
+
 impl Prefix {
     pub fn is_match(&self, c1: u8, c2: u8, c3: u8) -> bool {
         match self {
@@ -37,6 +46,24 @@ impl Prefix {
         }
     }
 
+    /// Characters matched at each of the first three positions.
+    pub fn ranges(&self) -> (u8, (u8, u8), (u8, u8)) {
+        match *self {
+            Prefix::OneAll(p1) => (p1, FULL_RANGE, FULL_RANGE),
+            Prefix::TwoRange(p1, p2) => (p1, p2, FULL_RANGE),
+            Prefix::TwoSpecified(p1, p2) => (p1, (p2, p2), FULL_RANGE),
+            Prefix::ThreeRange(p1, p2, p3) => (p1, (p2, p2), p3),
+        }
+    }
+
+    /// Whether any callsign matches both prefixes.
+    pub fn overlaps(&self, other: &Prefix) -> bool {
+        let (l1, l2, l3) = self.ranges();
+        let (r1, r2, r3) = other.ranges();
+        let intersects = |(ls, le): (u8, u8), (rs, re): (u8, u8)| ls <= re && rs <= le;
+        l1 == r1 && intersects(l2, r2) && intersects(l3, r3)
+    }
+
     fn point_cmp(x: u8, (l, r): (u8, u8)) -> Ordering {
         assert!(l <= r);
         if (l..=r).contains(&x) {
@@ -79,6 +106,33 @@ impl Display for Prefix {
     }
 }
 
+impl FromStr for Prefix {
+    type Err = InvalidPrefix;
+
+    /// Parses the format of `Display`, such as `W`, `JA-JS`, `3A` or `3DA-3DM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidPrefix(s.to_string());
+        let upper = s.trim().to_ascii_uppercase();
+        if !upper
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        {
+            return Err(invalid());
+        }
+        match upper.as_bytes() {
+            [p1] => Ok(Prefix::OneAll(*p1)),
+            [p1, p2] => Ok(Prefix::TwoSpecified(*p1, *p2)),
+            [p1, p2s, b'-', q1, p2e] if p1 == q1 && p2s <= p2e => {
+                Ok(Prefix::TwoRange(*p1, (*p2s, *p2e)))
+            }
+            [p1, p2, p3s, b'-', q1, q2, p3e] if p1 == q1 && p2 == q2 && p3s <= p3e => {
+                Ok(Prefix::ThreeRange(*p1, *p2, (*p3s, *p3e)))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Prefix;
@@ -90,4 +144,26 @@ mod tests {
         assert!(Prefix::TwoSpecified(b'3', b'A').is_match(b'3', b'A', b'0'));
         assert!(Prefix::ThreeRange(b'3', b'D', (b'A', b'M')).is_match(b'3', b'D', b'C'));
     }
+
+    #[test]
+    fn parses_displayed_prefix() {
+        for prefix in [
+            Prefix::OneAll(b'W'),
+            Prefix::TwoRange(b'J', (b'A', b'S')),
+            Prefix::TwoSpecified(b'3', b'A'),
+            Prefix::ThreeRange(b'3', b'D', (b'A', b'M')),
+        ] {
+            assert_eq!(prefix.to_string().parse::<Prefix>(), Ok(prefix));
+        }
+        assert!("JA-KS".parse::<Prefix>().is_err());
+        assert!("JS-JA".parse::<Prefix>().is_err());
+    }
+
+    #[test]
+    fn checks_overlap() {
+        let japan: Prefix = "JA-JS".parse().expect("valid prefix");
+        assert!(japan.overlaps(&"JD".parse().expect("valid prefix")));
+        assert!(japan.overlaps(&Prefix::OneAll(b'J')));
+        assert!(!japan.overlaps(&"JT-JV".parse().expect("valid prefix")));
+    }
 }
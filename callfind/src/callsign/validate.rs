@@ -0,0 +1,221 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    callsign::{Callsign, CallsignAssign, CallsignError, CallsignSuffix, allocated_prefix_len},
+    japan::{LicenseSeries, classify_japanese},
+};
+
+/// Kind of station inferred from the callsign structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StationType {
+    Amateur,
+
+    /// Suffix longer than 4 characters, several digits after the allocated prefix such as `YT2000A`,
+    /// or `8J` series.
+    SpecialEvent,
+
+    /// One-letter suffix such as `K3A` or `CN2R`.
+    ContestSpecial,
+
+    /// `/B`
+    Beacon,
+
+    /// `/MM`
+    MaritimeMobile,
+
+    /// `/AM`
+    AeronauticalMobile,
+}
+
+/// Pattern which typically appears in busted callsigns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CallsignIssue {
+    InvalidCharacter,
+    Malformed,
+
+    /// No digit separates prefix and suffix, such as `JAZZZ`.
+    MissingDigit,
+
+    /// No letter follows the digit, such as `JA1`.
+    MissingSuffix,
+
+    LeadingZero,
+    LeadingQ,
+
+    /// More than 3 characters precede the digit.
+    PrefixTooLong,
+
+    /// The prefix is not allocated to any country.
+    UnassignedPrefix(String),
+}
+
+/// Result of callsign validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallsignValidation {
+    /// `None` if the callsign cannot be parsed.
+    pub station_type: Option<StationType>,
+    pub issues: Vec<CallsignIssue>,
+}
+
+impl CallsignValidation {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for StationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            StationType::Amateur => write!(f, "amateur"),
+            StationType::SpecialEvent => write!(f, "special event"),
+            StationType::ContestSpecial => write!(f, "contest special"),
+            StationType::Beacon => write!(f, "beacon"),
+            StationType::MaritimeMobile => write!(f, "maritime mobile"),
+            StationType::AeronauticalMobile => write!(f, "aeronautical mobile"),
+        }
+    }
+}
+
+impl Display for CallsignIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CallsignIssue::InvalidCharacter => write!(f, "invalid character"),
+            CallsignIssue::Malformed => write!(f, "malformed designators"),
+            CallsignIssue::MissingDigit => write!(f, "missing digit"),
+            CallsignIssue::MissingSuffix => write!(f, "missing suffix"),
+            CallsignIssue::LeadingZero => write!(f, "leading 0"),
+            CallsignIssue::LeadingQ => write!(f, "leading Q"),
+            CallsignIssue::PrefixTooLong => write!(f, "prefix too long"),
+            CallsignIssue::UnassignedPrefix(prefix) => write!(f, "unassigned prefix {prefix}"),
+        }
+    }
+}
+
+/// Validates the callsign against ITU Radio Regulations Article 19 structure:
+/// prefix of 1 to 3 characters, a separating digit, and a suffix.
+pub fn validate_callsign(callsign: &str) -> CallsignValidation {
+    let upper = callsign.trim().to_ascii_uppercase();
+    let parsed = match upper.parse::<Callsign>() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let mut issues = leading_issues(&upper);
+            issues.push(match e {
+                CallsignError::InvalidCharacter => CallsignIssue::InvalidCharacter,
+                CallsignError::NoBaseCallsign if !upper.bytes().any(|c| c.is_ascii_digit()) => {
+                    CallsignIssue::MissingDigit
+                }
+                CallsignError::NoBaseCallsign => CallsignIssue::MissingSuffix,
+                CallsignError::Empty | CallsignError::EmptyPart | CallsignError::Ambiguous(_) => {
+                    CallsignIssue::Malformed
+                }
+            });
+            return CallsignValidation {
+                station_type: None,
+                issues,
+            };
+        }
+    };
+
+    let base = parsed.base();
+    let base_prefix = parsed.base_prefix();
+    let letters = base_prefix.trim_end_matches(|c: char| c.is_ascii_digit());
+    // The allocated prefix may end with a digit, such as `A6` in `A61AB`
+    let digits = match allocated_prefix_len(base.as_bytes()) {
+        Some(len) => base_prefix
+            .bytes()
+            .skip(len)
+            .filter(u8::is_ascii_digit)
+            .count(),
+        None => base_prefix.len() - letters.len(),
+    };
+    let suffix_len = base.len() - base_prefix.len();
+
+    let mut issues = leading_issues(base);
+    if let Some(prefix) = parsed.prefix_override() {
+        issues.extend(leading_issues(prefix));
+    }
+    if letters.len() > 3 {
+        issues.push(CallsignIssue::PrefixTooLong);
+    }
+    // Unavailable prefixes are reserved for other services, and flagged above if leading
+    if issues.is_empty() && parsed.lookup_area() == CallsignAssign::Unassigned {
+        issues.push(CallsignIssue::UnassignedPrefix(parsed.effective_prefix()));
+    }
+
+    let japanese_series = classify_japanese(&upper).map(|j| j.series);
+    let station_type = if parsed.has_suffix(&CallsignSuffix::MaritimeMobile) {
+        StationType::MaritimeMobile
+    } else if parsed.has_suffix(&CallsignSuffix::AeronauticalMobile) {
+        StationType::AeronauticalMobile
+    } else if parsed.has_suffix(&CallsignSuffix::Other("B".to_string())) {
+        StationType::Beacon
+    } else if digits > 1 || suffix_len > 4 || japanese_series == Some(LicenseSeries::SpecialEvent) {
+        StationType::SpecialEvent
+    } else if suffix_len == 1 {
+        StationType::ContestSpecial
+    } else {
+        StationType::Amateur
+    };
+
+    CallsignValidation {
+        station_type: Some(station_type),
+        issues,
+    }
+}
+
+fn leading_issues(text: &str) -> Vec<CallsignIssue> {
+    match text.as_bytes().first() {
+        Some(b'0') => vec![CallsignIssue::LeadingZero],
+        Some(b'Q') => vec![CallsignIssue::LeadingQ],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CallsignIssue, StationType, validate_callsign};
+
+    #[test]
+    fn classifies_station_types() {
+        let station_type = |c| validate_callsign(c).station_type;
+        assert_eq!(station_type("JA1ABC"), Some(StationType::Amateur));
+        assert_eq!(station_type("8J1ITU"), Some(StationType::SpecialEvent));
+        assert_eq!(station_type("YT2000A"), Some(StationType::SpecialEvent));
+        assert_eq!(station_type("GB100RSGB"), Some(StationType::SpecialEvent));
+        assert_eq!(station_type("K3A"), Some(StationType::ContestSpecial));
+        assert_eq!(station_type("JA1IGY/B"), Some(StationType::Beacon));
+        assert_eq!(station_type("JA1ABC/MM"), Some(StationType::MaritimeMobile));
+        assert_eq!(
+            station_type("JA1ABC/AM"),
+            Some(StationType::AeronauticalMobile)
+        );
+        assert!(validate_callsign("JA1ABC/P").is_valid());
+
+        for callsign in [
+            "A61AB", "T88XX", "V85AB", "S21AB", "9M2AB", "E21ABC", "3DA0RU",
+        ] {
+            assert_eq!(
+                station_type(callsign),
+                Some(StationType::Amateur),
+                "{callsign}"
+            );
+        }
+        assert_eq!(station_type("A6100A"), Some(StationType::SpecialEvent));
+        assert_eq!(station_type("OP0LE"), Some(StationType::Amateur));
+    }
+
+    #[test]
+    fn flags_busted_calls() {
+        let issues = |c| validate_callsign(c).issues;
+        assert_eq!(issues("JAABC"), vec![CallsignIssue::MissingDigit]);
+        assert_eq!(issues("JA1"), vec![CallsignIssue::MissingSuffix]);
+        assert_eq!(issues("0A1ABC"), vec![CallsignIssue::LeadingZero]);
+        assert_eq!(issues("QA1ABC"), vec![CallsignIssue::LeadingQ]);
+        assert_eq!(issues("JAAA1ABC"), vec![CallsignIssue::PrefixTooLong]);
+        assert_eq!(issues("JA1 ABC"), vec![CallsignIssue::InvalidCharacter]);
+        assert_eq!(
+            issues("E91ABC"),
+            vec![CallsignIssue::UnassignedPrefix("E91".to_string())]
+        );
+    }
+}
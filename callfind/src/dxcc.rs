@@ -0,0 +1,17 @@
+mod continent;
+mod cty;
+mod data;
+mod table;
+
+pub use crate::dxcc::{
+    continent::{Continent, InvalidContinent},
+    cty::{CtyError, CtyFile},
+    table::{DxccEntity, DxccInfo, DxccTable},
+};
+
+pub(crate) use crate::dxcc::table::DxccMapping;
+
+/// Resolves DXCC entity of the callsign with the bundled table.
+pub fn lookup_dxcc(callsign: &str) -> Option<DxccInfo<'static>> {
+    DxccTable::builtin().lookup(callsign)
+}
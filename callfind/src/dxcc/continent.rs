@@ -0,0 +1,55 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("invalid continent specifier")]
+pub struct InvalidContinent;
+
+impl FromStr for Continent {
+    type Err = InvalidContinent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AF" | "af" => Ok(Continent::Africa),
+            "AN" | "an" => Ok(Continent::Antarctica),
+            "AS" | "as" => Ok(Continent::Asia),
+            "EU" | "eu" => Ok(Continent::Europe),
+            "NA" | "na" => Ok(Continent::NorthAmerica),
+            "OC" | "oc" => Ok(Continent::Oceania),
+            "SA" | "sa" => Ok(Continent::SouthAmerica),
+            _ => Err(InvalidContinent),
+        }
+    }
+}
+
+impl Display for Continent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Continent::Africa => write!(f, "AF"),
+            Continent::Antarctica => write!(f, "AN"),
+            Continent::Asia => write!(f, "AS"),
+            Continent::Europe => write!(f, "EU"),
+            Continent::NorthAmerica => write!(f, "NA"),
+            Continent::Oceania => write!(f, "OC"),
+            Continent::SouthAmerica => write!(f, "SA"),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::{borrow::Cow, fs::read_to_string, io::Error as IoError, path::Path};
+
+use thiserror::Error as ThisError;
+
+use crate::dxcc::{Continent, DxccEntity, DxccMapping, DxccTable};
+
+#[derive(Debug, ThisError)]
+pub enum CtyError {
+    #[error("failed to read country file: {0}")]
+    Io(#[from] IoError),
+
+    #[error("invalid entity: {0}")]
+    InvalidEntity(String),
+
+    #[error("invalid prefix: {0}")]
+    InvalidPrefix(String),
+}
+
+/// Country file loaded at runtime, in `cty.dat` or `cty.csv` format.
+/// Longitudes in the file are west positive, and converted to east positive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CtyFile {
+    entities: Vec<DxccEntity>,
+    prefixes: Vec<DxccMapping>,
+    exact_calls: Vec<DxccMapping>,
+}
+
+impl CtyFile {
+    /// Loads the file, treating `*.csv` as `cty.csv` format and others as `cty.dat` format.
+    pub fn load(path: impl AsRef<Path>) -> Result<CtyFile, CtyError> {
+        let path = path.as_ref();
+        let text = read_to_string(path)?;
+        if path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("csv"))
+        {
+            CtyFile::parse_csv(&text)
+        } else {
+            CtyFile::parse_dat(&text)
+        }
+    }
+
+    /// Parses `cty.dat` format.
+    /// This format has no DXCC code, so it is taken from the bundled table by primary prefix
+    /// or else by entity name, and left unknown for entities outside it.
+    pub fn parse_dat(text: &str) -> Result<CtyFile, CtyError> {
+        let builtin = DxccTable::builtin();
+        let mut file = CtyFile::empty();
+        for record in text.split(';').map(str::trim).filter(|r| !r.is_empty()) {
+            let fields: Vec<_> = record.splitn(9, ':').map(str::trim).collect();
+            let [name, cq, itu, continent, lat, lon, _, prefix, patterns] = fields[..] else {
+                return Err(CtyError::InvalidEntity(record.to_string()));
+            };
+            let entities = builtin.entities();
+            let code = entities
+                .iter()
+                .find(|e| e.prefix.eq_ignore_ascii_case(prefix))
+                .or_else(|| entities.iter().find(|e| e.name == name))
+                .and_then(|e| e.code);
+            file.push_entity(
+                [prefix, name, cq, itu, continent, lat, lon],
+                code,
+                patterns.split(',').map(str::trim),
+            )?;
+        }
+        file.sort();
+        Ok(file)
+    }
+
+    /// Parses `cty.csv` format.
+    pub fn parse_csv(text: &str) -> Result<CtyFile, CtyError> {
+        let mut file = CtyFile::empty();
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let columns: Vec<_> = line.splitn(10, ',').collect();
+            let [
+                prefix,
+                name,
+                code,
+                continent,
+                cq,
+                itu,
+                lat,
+                lon,
+                _,
+                patterns,
+            ] = columns[..]
+            else {
+                return Err(CtyError::InvalidEntity(line.to_string()));
+            };
+            let patterns = patterns.trim_end_matches(';');
+            let code = code
+                .parse()
+                .map_err(|_| CtyError::InvalidEntity(name.to_string()))?;
+            file.push_entity(
+                [prefix, name, cq, itu, continent, lat, lon],
+                Some(code),
+                patterns.split_whitespace(),
+            )?;
+        }
+        file.sort();
+        Ok(file)
+    }
+
+    /// Resolver with the loaded entities.
+    pub fn table(&self) -> DxccTable<'_> {
+        DxccTable::new(&self.entities, &self.prefixes, &self.exact_calls)
+    }
+
+    fn empty() -> CtyFile {
+        CtyFile {
+            entities: vec![],
+            prefixes: vec![],
+            exact_calls: vec![],
+        }
+    }
+
+    /// Adds an entity from `[prefix, name, cq, itu, continent, lat, lon]`.
+    fn push_entity<'a>(
+        &mut self,
+        [prefix, name, cq, itu, continent, lat, lon]: [&str; 7],
+        code: Option<u16>,
+        patterns: impl Iterator<Item = &'a str>,
+    ) -> Result<(), CtyError> {
+        // WAE-only entities are not DXCC entities
+        if prefix.starts_with('*') {
+            return Ok(());
+        }
+        let invalid = || CtyError::InvalidEntity(name.to_string());
+        let longitude: f64 = lon.parse().map_err(|_| invalid())?;
+        let entity = DxccEntity {
+            code,
+            prefix: Cow::Owned(prefix.to_string()),
+            name: Cow::Owned(name.to_string()),
+            continent: continent.parse().map_err(|_| invalid())?,
+            cq_zone: cq.parse().map_err(|_| invalid())?,
+            itu_zone: itu.parse().map_err(|_| invalid())?,
+            latitude: lat.parse().map_err(|_| invalid())?,
+            longitude: -longitude,
+        };
+        let index = self.entities.len();
+        self.entities.push(entity);
+
+        for token in patterns.filter(|t| !t.is_empty()) {
+            let mapping = parse_mapping(token, index)?;
+            if token.starts_with('=') {
+                self.exact_calls.push(mapping);
+            } else {
+                self.prefixes.push(mapping);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sorts mappings for binary search, keeping the first one of duplicated patterns.
+    fn sort(&mut self) {
+        for mappings in [&mut self.prefixes, &mut self.exact_calls] {
+            mappings.sort_by(|l, r| l.pattern.cmp(&r.pattern));
+            mappings.dedup_by(|r, l| l.pattern == r.pattern);
+        }
+    }
+}
+
+/// Parses `=JD1BMM(27)[90]<24.28/-153.97>{OC}~-10.0~` into a mapping.
+fn parse_mapping(token: &str, entity: usize) -> Result<DxccMapping, CtyError> {
+    let invalid = || CtyError::InvalidPrefix(token.to_string());
+    let body = token.strip_prefix('=').unwrap_or(token);
+    let pattern_end = body.find(['(', '[', '<', '{', '~']).unwrap_or(body.len());
+    let pattern = body[..pattern_end].to_ascii_uppercase();
+    if pattern.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut mapping = DxccMapping {
+        pattern: Cow::Owned(pattern),
+        entity,
+        cq_zone: None,
+        itu_zone: None,
+        continent: None,
+        location: None,
+    };
+    let mut rest = &body[pattern_end..];
+    while let Some(open) = rest.chars().next() {
+        let close = match open {
+            '(' => ')',
+            '[' => ']',
+            '<' => '>',
+            '{' => '}',
+            '~' => '~',
+            _ => return Err(invalid()),
+        };
+        let (value, remaining) = rest[1..].split_once(close).ok_or_else(invalid)?;
+        match open {
+            '(' => mapping.cq_zone = Some(value.parse().map_err(|_| invalid())?),
+            '[' => mapping.itu_zone = Some(value.parse().map_err(|_| invalid())?),
+            '<' => {
+                let (lat, lon) = value.split_once('/').ok_or_else(invalid)?;
+                let lat: f64 = lat.parse().map_err(|_| invalid())?;
+                let lon: f64 = lon.parse().map_err(|_| invalid())?;
+                mapping.location = Some((lat, -lon));
+            }
+            '{' => mapping.continent = Some(value.parse::<Continent>().map_err(|_| invalid())?),
+            _ => (),
+        }
+        rest = remaining;
+    }
+
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CtyFile;
+    use crate::dxcc::{Continent, DxccTable};
+
+    const CTY_DAT: &str = "\
+Japan:                    25:  45:  AS:   36.40:  -138.38:    -9.0:  JA:
+    7J,7K,JA,JE,JH,=JA1XYZ/7(26)[48]<33.00/-130.00>;
+Ogasawara:                27:  45:  AS:   27.05:  -142.20:   -10.0:  JD1/O:
+    JD1;
+Minami Torishima:         27:  90:  OC:   24.28:  -153.97:   -10.0:  JD1/M:
+    =JD1BMM{OC};
+Wales:                    14:  27:  EU:   52.28:     3.73:     0.0:  GW:
+    2W,GW,MW;
+Sicily:                   15:  28:  EU:   37.50:   -14.00:    -1.0:  *IT9:
+    IT9;
+";
+
+    /// Records as distributed, with wrapped prefix lists and lowercase primary prefixes.
+    const CTY_DAT_EXCERPT: &str = "\
+Sov Mil Order of Malta:   15:  28:  EU:   41.90:   -12.43:    -1.0:  1A:
+    1A;
+Spratly Islands:          26:  50:  AS:    9.88:  -114.23:    -8.0:  1S:
+    1S,9M0,BM9S,BN9S,BO9S,BP9S,BQ9S,BU9S,BV9S,BW9S,BX9S;
+Aland Islands:            15:  18:  EU:   60.13:   -20.37:    -2.0:  OH0:
+    OF0,OG0,OH0,OI0,=OH0AM/LH;
+Finland:                  15:  18:  EU:   63.78:   -27.08:    -2.0:  OH:
+    OF,OG,OH,OI,OJ,
+    =OH2BH/LH,=OH6AB/LH;
+Wales:                    14:  27:  EU:   52.28:     3.73:     0.0:  GW:
+    2C,2W,GC,GW,MC,MW,=GB0CVA(14)[27];
+Sicily:                   15:  28:  EU:   37.50:   -14.00:    -1.0:  *IT9:
+    IT9,IW9;
+Ogasawara:                27:  45:  AS:   27.05:  -142.20:   -10.0:  JD/o:
+    JD1,=JD1BLK;
+";
+
+    #[test]
+    fn matches_builtin_table() {
+        let file = CtyFile::parse_csv(include_str!("../../cty.csv")).expect("valid cty.csv");
+        let builtin = DxccTable::builtin();
+        assert_eq!(file.table().entities(), builtin.entities());
+        for call in [
+            "JA1XXX",
+            "JD1BMM",
+            "KH6/JA1XXX",
+            "W6AA",
+            "CE0ZAA",
+            "R0AA",
+            "UA2FA",
+        ] {
+            assert_eq!(file.table().lookup(call), builtin.lookup(call), "{call}");
+        }
+    }
+
+    #[test]
+    fn parses_cty_dat() {
+        let file = CtyFile::parse_dat(CTY_DAT).expect("valid cty.dat");
+        let table = file.table();
+        assert_eq!(table.entities().len(), 4);
+        assert_eq!(table.lookup("JH1AAA").and_then(|d| d.code), Some(339));
+        assert_eq!(table.lookup("JD1ABC").and_then(|d| d.code), Some(192));
+        assert_eq!(table.lookup("IT9AAA"), None);
+
+        let wales = table.lookup("GW3ABC").expect("must resolve");
+        assert_eq!((wales.name, wales.code), ("Wales", Some(294)));
+        assert_eq!(table.entity(0).map(|e| e.name.as_ref()), None);
+
+        let minami = table.lookup("JD1BMM").expect("must resolve");
+        assert_eq!(minami.code, Some(177));
+        assert_eq!(minami.continent, Continent::Oceania);
+
+        let exception = table.lookup("JA1XYZ/7").expect("must resolve");
+        assert_eq!((exception.cq_zone, exception.itu_zone), (26, 48));
+        assert_eq!((exception.latitude, exception.longitude), (33.0, 130.0));
+    }
+
+    #[test]
+    fn takes_codes_from_builtin_table() {
+        let file = CtyFile::parse_dat(CTY_DAT_EXCERPT).expect("valid cty.dat");
+        let table = file.table();
+        assert_eq!(table.entities().len(), 6);
+        for (call, code) in [
+            ("1A0KM", 246),
+            ("1S0X", 247),
+            ("OH0AB", 5),
+            ("OH0AM/LH", 5),
+            ("OH2ABC", 224),
+            ("MW0XYZ", 294),
+            ("JD1ABC", 192),
+        ] {
+            assert_eq!(
+                table.lookup(call).and_then(|d| d.code),
+                Some(code),
+                "{call}"
+            );
+        }
+        assert_eq!(table.lookup("IT9ABC"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(CtyFile::parse_dat("Japan: 25: 45: AS: JA;").is_err());
+        assert!(CtyFile::parse_csv("JA,Japan,339,XX,25,45,36.40,-138.38,-9.0,JA;").is_err());
+        assert!(CtyFile::parse_csv("JA,Japan,339,AS,25,45,36.40,-138.38,-9.0,JA(25;").is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::{
+    callsign::{Callsign, CallsignSuffix},
+    dxcc::{Continent, data},
+};
+
+/// DXCC entity with its default zones and location.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DxccEntity {
+    /// ADIF DXCC entity code, unknown for entities from `cty.dat` outside the bundled table.
+    pub code: Option<u16>,
+
+    /// Primary prefix such as `JA` or `JD1/O`.
+    pub prefix: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub continent: Continent,
+    pub cq_zone: u8,
+    pub itu_zone: u8,
+
+    /// Latitude in degrees, north positive.
+    pub latitude: f64,
+
+    /// Longitude in degrees, east positive.
+    pub longitude: f64,
+}
+
+/// Prefix or exact callsign mapped to an entity, with overrides of it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DxccMapping {
+    pub pattern: Cow<'static, str>,
+    pub entity: usize,
+    pub cq_zone: Option<u8>,
+    pub itu_zone: Option<u8>,
+    pub continent: Option<Continent>,
+    pub location: Option<(f64, f64)>,
+}
+
+/// Resolved DXCC entity of a callsign.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DxccInfo<'t> {
+    pub code: Option<u16>,
+    pub name: &'t str,
+    pub continent: Continent,
+    pub cq_zone: u8,
+    pub itu_zone: u8,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Entity table with prefixes and exact callsigns, each sorted for binary search.
+#[derive(Debug, Clone, Copy)]
+pub struct DxccTable<'t> {
+    entities: &'t [DxccEntity],
+    prefixes: &'t [DxccMapping],
+    exact_calls: &'t [DxccMapping],
+}
+
+impl<'t> DxccTable<'t> {
+    /// Table bundled at build time.
+    pub fn builtin() -> DxccTable<'static> {
+        DxccTable {
+            entities: data::ENTITIES,
+            prefixes: data::PREFIXES,
+            exact_calls: data::EXACT_CALLS,
+        }
+    }
+
+    pub(crate) fn new(
+        entities: &'t [DxccEntity],
+        prefixes: &'t [DxccMapping],
+        exact_calls: &'t [DxccMapping],
+    ) -> DxccTable<'t> {
+        DxccTable {
+            entities,
+            prefixes,
+            exact_calls,
+        }
+    }
+
+    pub fn entities(&self) -> &'t [DxccEntity] {
+        self.entities
+    }
+
+    pub fn entity(&self, code: u16) -> Option<&'t DxccEntity> {
+        self.entities.iter().find(|e| e.code == Some(code))
+    }
+
+    /// Resolves DXCC entity of the callsign.
+    /// Exact callsigns take precedence, then the longest prefix of the effective callsign.
+    /// Maritime and aeronautical mobile operations are not in any entity.
+    pub fn lookup(&self, callsign: &str) -> Option<DxccInfo<'t>> {
+        let upper = callsign.trim().to_ascii_uppercase();
+        if let Some(mapping) = self.find_exact(&upper) {
+            return Some(self.resolve(mapping));
+        }
+
+        let parsed: Callsign = upper.parse().ok()?;
+        if parsed.has_suffix(&CallsignSuffix::MaritimeMobile)
+            || parsed.has_suffix(&CallsignSuffix::AeronauticalMobile)
+        {
+            return None;
+        }
+        if parsed.prefix_override().is_none()
+            && parsed.area_override().is_none()
+            && let Some(mapping) = self.find_exact(parsed.base())
+        {
+            return Some(self.resolve(mapping));
+        }
+
+        let key = parsed.lookup_key();
+        (1..=key.len()).rev().find_map(|len| {
+            let i = self
+                .prefixes
+                .binary_search_by(|m| m.pattern.as_ref().cmp(&key[..len]))
+                .ok()?;
+            Some(self.resolve(&self.prefixes[i]))
+        })
+    }
+
+    fn find_exact(&self, callsign: &str) -> Option<&'t DxccMapping> {
+        let i = self
+            .exact_calls
+            .binary_search_by(|m| m.pattern.as_ref().cmp(callsign))
+            .ok()?;
+        Some(&self.exact_calls[i])
+    }
+
+    fn resolve(&self, mapping: &DxccMapping) -> DxccInfo<'t> {
+        let entity = &self.entities[mapping.entity];
+        let (latitude, longitude) = mapping
+            .location
+            .unwrap_or((entity.latitude, entity.longitude));
+        DxccInfo {
+            code: entity.code,
+            name: &entity.name,
+            continent: mapping.continent.unwrap_or(entity.continent),
+            cq_zone: mapping.cq_zone.unwrap_or(entity.cq_zone),
+            itu_zone: mapping.itu_zone.unwrap_or(entity.itu_zone),
+            latitude,
+            longitude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DxccTable;
+    use crate::dxcc::Continent;
+
+    #[test]
+    fn resolves_entities() {
+        let table = DxccTable::builtin();
+        let japan = table.lookup("JA1XXX").expect("must resolve");
+        assert_eq!(japan.code, Some(339));
+        assert_eq!(japan.continent, Continent::Asia);
+        assert_eq!((japan.cq_zone, japan.itu_zone), (25, 45));
+        assert!(japan.longitude > 0.0);
+
+        assert_eq!(table.lookup("JD1ABC").and_then(|d| d.code), Some(192));
+        assert_eq!(table.lookup("KH6XYZ").and_then(|d| d.code), Some(110));
+        assert_eq!(table.lookup("CE0YAA").and_then(|d| d.code), Some(47));
+        assert_eq!(table.lookup("CE3AA").and_then(|d| d.code), Some(112));
+    }
+
+    #[test]
+    fn prefers_exact_calls_and_overrides() {
+        let table = DxccTable::builtin();
+        let minami = table.lookup("JD1BMM").expect("must resolve");
+        assert_eq!(minami.code, Some(177));
+        assert_eq!(minami.continent, Continent::Oceania);
+
+        let west = table.lookup("W6AA").expect("must resolve");
+        assert_eq!((west.code, west.cq_zone, west.itu_zone), (Some(291), 3, 6));
+    }
+
+    #[test]
+    fn resolves_designators() {
+        let table = DxccTable::builtin();
+        assert_eq!(table.lookup("KH6/JA1XXX").and_then(|d| d.code), Some(110));
+        assert_eq!(table.lookup("JA1XXX/JD1").and_then(|d| d.code), Some(192));
+        assert_eq!(table.lookup("JA1XXX/P").and_then(|d| d.code), Some(339));
+        assert_eq!(table.lookup("JA1XXX/MM"), None);
+        assert_eq!(
+            table.lookup("K1AB/6").map(|d| d.cq_zone),
+            Some(3),
+            "area override moves the zone"
+        );
+    }
+
+    #[test]
+    fn resolves_nested_entities() {
+        let table = DxccTable::builtin();
+        for (callsign, code) in [
+            ("GW3ABC", 294),
+            ("MW0XYZ", 294),
+            ("GI4AAA", 265),
+            ("GD6ZZ", 114),
+            ("VK9XX", 35),
+            ("FR5AB", 453),
+            ("IS0ABC", 225),
+            ("OH0AB", 5),
+            ("G3ABC", 223),
+            ("VK2ABC", 150),
+            ("F5ABC", 227),
+        ] {
+            assert_eq!(
+                table.lookup(callsign).and_then(|d| d.code),
+                Some(code),
+                "{callsign}"
+            );
+        }
+    }
+
+    #[test]
+    fn covers_all_entities() {
+        let table = DxccTable::builtin();
+        assert_eq!(table.entities().len(), 340);
+        assert_eq!(table.lookup("OH2ABC").and_then(|d| d.code), Some(224));
+        assert_eq!(table.lookup("SV1ABC").and_then(|d| d.code), Some(236));
+        assert_eq!(table.lookup("SV9ABC").and_then(|d| d.code), Some(40));
+        assert_eq!(table.lookup("5B4AAA").and_then(|d| d.code), Some(215));
+        assert_eq!(table.lookup("ZC4A").and_then(|d| d.code), Some(283));
+    }
+
+    #[test]
+    fn resolves_russian_districts() {
+        let table = DxccTable::builtin();
+        assert_eq!(table.lookup("RK9AA").and_then(|d| d.code), Some(15));
+        assert_eq!(table.lookup("UB0AAA").and_then(|d| d.code), Some(15));
+        assert_eq!(table.lookup("RV2FA").and_then(|d| d.code), Some(126));
+        assert_eq!(table.lookup("RN3AA").and_then(|d| d.code), Some(54));
+        assert_eq!(table.lookup("UR5ABC").and_then(|d| d.code), Some(288));
+        assert_eq!(table.lookup("UN7AB").and_then(|d| d.code), Some(130));
+    }
+}
@@ -1,5 +1,7 @@
 mod error;
+mod geodesic;
 mod inner;
+mod precision;
 
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -9,7 +11,31 @@ use std::{
 use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, serde_as};
 
-use crate::grid_locator::{error::GridLocatorError, inner::GridLocatorInner};
+use crate::grid_locator::inner::GridLocatorInner;
+
+pub use crate::grid_locator::{
+    error::GridLocatorError,
+    geodesic::{EarthModel, GeoPath},
+    precision::GridPrecision,
+};
+
+/// Width of blocks for each pair in degrees, from field to subsquare 3.
+const BLOCK_LONGITUDES: [f64; 5] = [20.0, 2.0, 2.0 / 24.0, 2.0 / 240.0, 2.0 / 5760.0];
+
+/// Number of divisions for each pair, from field to subsquare 3.
+const PAIR_RADICES: [u32; 5] = [18, 10, 24, 10, 24];
+
+/// Kilometers per degree of latitude on the mean sphere.
+const KM_PER_DEGREE: f64 = 111.19;
+
+/// Parses comma-separated grid locators such as `VUCC_GRIDS`.
+pub fn parse_grids(s: &str) -> Result<Vec<GridLocator>, GridLocatorError> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|g| !g.is_empty())
+        .map(str::parse)
+        .collect()
+}
 
 #[serde_as]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -17,8 +43,12 @@ use crate::grid_locator::{error::GridLocatorError, inner::GridLocatorInner};
 pub struct GridLocator(#[serde_as(as = "DisplayFromStr")] GridLocatorInner);
 
 impl GridLocator {
-    /// Calculates grid locator from longitude and latitude.
-    pub fn from_lnglat(longitude: f64, latitude: f64) -> Result<GridLocator, GridLocatorError> {
+    /// Calculates grid locator with given precision from longitude and latitude.
+    pub fn from_lnglat(
+        longitude: f64,
+        latitude: f64,
+        precision: GridPrecision,
+    ) -> Result<GridLocator, GridLocatorError> {
         let offset_lng = longitude + 180.0;
         let offset_lat = latitude + 90.0;
         if !(0.0..360.0).contains(&offset_lng) || !(0.0..180.0).contains(&offset_lat) {
@@ -31,7 +61,7 @@ impl GridLocator {
         let lng_letters = Self::calculate_indices(scaled_lng);
         let lat_letters = Self::calculate_indices(scaled_lat);
 
-        Ok(GridLocator(GridLocatorInner::ToSub3([
+        let full = GridLocatorInner::ToSub3([
             lng_letters[0],
             lat_letters[0],
             lng_letters[1],
@@ -42,7 +72,185 @@ impl GridLocator {
             lat_letters[3],
             lng_letters[4],
             lat_letters[4],
-        ])))
+        ]);
+        Ok(GridLocator(full.truncated(precision)))
+    }
+
+    pub fn precision(&self) -> GridPrecision {
+        match self.0 {
+            GridLocatorInner::ToSquare(_) => GridPrecision::Square,
+            GridLocatorInner::ToSub1(_) => GridPrecision::Sub1,
+            GridLocatorInner::ToSub2(_) => GridPrecision::Sub2,
+            GridLocatorInner::ToSub3(_) => GridPrecision::Sub3,
+        }
+    }
+
+    /// Shortens to given precision. Coarser locators are returned as is.
+    pub fn truncate_to(&self, precision: GridPrecision) -> GridLocator {
+        GridLocator(self.0.truncated(precision))
+    }
+
+    /// Shortens or extends to given precision.
+    /// Extended locators point the block at the center of the original one.
+    pub fn with_precision(&self, precision: GridPrecision) -> GridLocator {
+        if precision <= self.precision() {
+            return self.truncate_to(precision);
+        }
+        let (longitude, latitude) = self.center();
+        GridLocator::from_lnglat(longitude, latitude, precision).expect("center must be in range")
+    }
+
+    /// Longitude and latitude of the south-west and north-east corners.
+    pub fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let mut longitude = -180.0;
+        let mut latitude = -90.0;
+        for (pair, width) in self.0.indices().chunks(2).zip(BLOCK_LONGITUDES) {
+            longitude += pair[0] as f64 * width;
+            latitude += pair[1] as f64 * width / 2.0;
+        }
+
+        let width = BLOCK_LONGITUDES[self.0.indices().len() / 2 - 1];
+        (
+            (longitude, latitude),
+            (longitude + width, latitude + width / 2.0),
+        )
+    }
+
+    /// Longitude and latitude of the center.
+    pub fn center(&self) -> (f64, f64) {
+        let ((west, south), (east, north)) = self.bounds();
+        ((west + east) / 2.0, (south + north) / 2.0)
+    }
+
+    /// Computes path between the centers of locators.
+    pub fn path_to(&self, other: &GridLocator, model: EarthModel) -> GeoPath {
+        GeoPath::between(self.center(), other.center(), model)
+    }
+
+    /// Whether the point lies in this locator, including its edges.
+    pub fn contains_lnglat(&self, (longitude, latitude): (f64, f64)) -> bool {
+        let ((west, south), (east, north)) = self.bounds();
+        (west..=east).contains(&longitude) && (south..=north).contains(&latitude)
+    }
+
+    /// Whether the other locator lies in this one. Locators contain themselves.
+    pub fn contains(&self, other: &GridLocator) -> bool {
+        other.0.indices().starts_with(self.0.indices())
+    }
+
+    /// Adjacent locators with the same precision, clockwise from north.
+    /// Longitude wraps at the antimeridian, and no locators exist beyond the poles.
+    pub fn neighbors(&self) -> Vec<GridLocator> {
+        const OFFSETS: [(i64, i64); 8] = [
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+        ];
+
+        let precision = self.precision();
+        let (columns, rows) = Self::block_counts(precision);
+        let (x, y) = self.block_xy();
+        OFFSETS
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let y = u32::try_from(y as i64 + dy).ok().filter(|&y| y < rows)?;
+                let x = (x as i64 + dx).rem_euclid(columns as i64) as u32;
+                Some(Self::from_block_xy(x, y, precision))
+            })
+            .collect()
+    }
+
+    /// Enumerates locators intersecting the box of south-west and north-east corners lazily,
+    /// since fine precisions yield millions of locators even for small boxes.
+    /// Boxes with west edge greater than east edge cross the antimeridian.
+    pub fn within_bounds(
+        (west, south): (f64, f64),
+        (east, north): (f64, f64),
+        precision: GridPrecision,
+    ) -> impl Iterator<Item = GridLocator> {
+        let (columns, rows) = Self::block_counts(precision);
+        let width = 360.0 / columns as f64;
+        let height = 180.0 / rows as f64;
+        let block = |degrees: f64, size: f64, count: u32| {
+            (degrees / size).floor().clamp(0.0, (count - 1) as f64) as u32
+        };
+
+        let (x_west, x_east) = (
+            block(west + 180.0, width, columns),
+            block(east + 180.0, width, columns),
+        );
+        let (y_south, y_north) = (
+            block(south + 90.0, height, rows),
+            block(north + 90.0, height, rows),
+        );
+        let span = if x_west <= x_east {
+            x_east - x_west + 1
+        } else {
+            columns - x_west + x_east + 1
+        };
+
+        (y_south..=y_north).flat_map(move |y| {
+            (0..span).map(move |i| Self::from_block_xy((x_west + i) % columns, y, precision))
+        })
+    }
+
+    /// Enumerates locators whose centers lie within the radius from the point on the mean sphere.
+    /// Locators are yielded lazily as `within_bounds`.
+    pub fn within_radius(
+        (longitude, latitude): (f64, f64),
+        radius_km: f64,
+        precision: GridPrecision,
+    ) -> impl Iterator<Item = GridLocator> {
+        let delta_lat = radius_km / KM_PER_DEGREE;
+        let south = (latitude - delta_lat).max(-90.0);
+        let north = (latitude + delta_lat).min(90.0);
+
+        // boxes reaching the poles or wider than the Earth span all longitudes
+        let widest_cos = south.to_radians().cos().min(north.to_radians().cos());
+        let delta_lng = radius_km / (KM_PER_DEGREE * widest_cos);
+        let (west, east) = if south <= -90.0 || north >= 90.0 || delta_lng >= 180.0 {
+            (-180.0, 180.0)
+        } else {
+            let wrap = |lng: f64| (lng + 180.0).rem_euclid(360.0) - 180.0;
+            (wrap(longitude - delta_lng), wrap(longitude + delta_lng))
+        };
+
+        Self::within_bounds((west, south), (east, north), precision).filter(move |g| {
+            let path = GeoPath::between((longitude, latitude), g.center(), EarthModel::Spherical);
+            path.distance_km <= radius_km
+        })
+    }
+
+    /// Numbers of blocks along longitude and latitude.
+    fn block_counts(precision: GridPrecision) -> (u32, u32) {
+        let pairs = precision.characters() / 2;
+        let count: u32 = PAIR_RADICES[..pairs].iter().product();
+        (count, count)
+    }
+
+    /// Block position counted from the south-west corner of the Earth.
+    fn block_xy(&self) -> (u32, u32) {
+        let pairs = self.0.indices().chunks(2).zip(PAIR_RADICES);
+        pairs.fold((0, 0), |(x, y), (pair, radix)| {
+            (x * radix + pair[0] as u32, y * radix + pair[1] as u32)
+        })
+    }
+
+    fn from_block_xy(mut x: u32, mut y: u32, precision: GridPrecision) -> GridLocator {
+        let pairs = precision.characters() / 2;
+        let mut indices = [0; 10];
+        for (i, radix) in PAIR_RADICES[..pairs].iter().enumerate().rev() {
+            indices[i * 2] = (x % radix) as u8;
+            indices[i * 2 + 1] = (y % radix) as u8;
+            x /= radix;
+            y /= radix;
+        }
+        GridLocator(GridLocatorInner::ToSub3(indices).truncated(precision))
     }
 
     fn calculate_indices(scaled_value: usize) -> [u8; 5] {
@@ -67,3 +275,118 @@ impl Display for GridLocator {
         self.0.fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{EarthModel, GridLocator, GridPrecision, parse_grids};
+
+    fn assert_near((lng, lat): (f64, f64), (expected_lng, expected_lat): (f64, f64)) {
+        assert!((lng - expected_lng).abs() < 1e-9, "{lng} != {expected_lng}");
+        assert!((lat - expected_lat).abs() < 1e-9, "{lat} != {expected_lat}");
+    }
+
+    #[test]
+    fn calculates_with_precision() {
+        let gl = GridLocator::from_lnglat(139.767, 35.681, GridPrecision::Sub3).unwrap();
+        assert_eq!(gl.to_string(), "PM95vq23ak");
+        let gl = GridLocator::from_lnglat(139.767, 35.681, GridPrecision::Sub1).unwrap();
+        assert_eq!(gl.to_string(), "PM95vq");
+        assert_eq!(gl.precision(), GridPrecision::Sub1);
+        assert!(GridLocator::from_lnglat(180.0, 0.0, GridPrecision::Square).is_err());
+    }
+
+    #[test]
+    fn calculates_bounds() {
+        let gl: GridLocator = "PM95".parse().unwrap();
+        assert_near(gl.bounds().0, (138.0, 35.0));
+        assert_near(gl.bounds().1, (140.0, 36.0));
+        assert_near(gl.center(), (139.0, 35.5));
+
+        let gl: GridLocator = "JJ00aa".parse().unwrap();
+        assert_near(gl.center(), (1.0 / 24.0, 1.0 / 48.0));
+    }
+
+    #[test]
+    fn changes_precision() {
+        let gl: GridLocator = "PM95vq07ab".parse().unwrap();
+        assert_eq!(gl.truncate_to(GridPrecision::Square).to_string(), "PM95");
+        assert_eq!(gl.truncate_to(GridPrecision::Sub1).to_string(), "PM95vq");
+
+        let gl: GridLocator = "PM95".parse().unwrap();
+        assert_eq!(gl.truncate_to(GridPrecision::Sub3), gl);
+        assert_eq!(gl.with_precision(GridPrecision::Sub1).to_string(), "PM95mm");
+    }
+
+    #[test]
+    fn computes_path_between_centers() {
+        let from: GridLocator = "PM95".parse().unwrap();
+        let to: GridLocator = "PM96".parse().unwrap();
+        let path = from.path_to(&to, EarthModel::Spherical);
+        assert!((path.distance_km - 111.2).abs() < 0.1, "{path:?}");
+        assert!(path.initial_bearing.abs() < 1e-9, "{path:?}");
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let gl: GridLocator = "PM95".parse().unwrap();
+        let neighbors: Vec<_> = gl.neighbors().iter().map(|g| g.to_string()).collect();
+        assert_eq!(
+            neighbors,
+            [
+                "PM96", "QM06", "QM05", "QM04", "PM94", "PM84", "PM85", "PM86"
+            ]
+        );
+
+        let gl: GridLocator = "RR99xx".parse().unwrap();
+        let neighbors: Vec<_> = gl.neighbors().iter().map(|g| g.to_string()).collect();
+        assert_eq!(
+            neighbors,
+            ["AR09ax", "AR09aw", "RR99xw", "RR99ww", "RR99wx"]
+        );
+    }
+
+    #[test]
+    fn checks_containment() {
+        let square: GridLocator = "PM95".parse().unwrap();
+        let sub1: GridLocator = "PM95vq".parse().unwrap();
+        assert!(square.contains(&sub1));
+        assert!(square.contains(&square));
+        assert!(!sub1.contains(&square));
+        assert!(!square.contains(&"PM96vq".parse().unwrap()));
+        assert!(square.contains_lnglat((139.767, 35.681)));
+        assert!(square.contains_lnglat((140.0, 36.0)));
+        assert!(!square.contains_lnglat((140.1, 35.5)));
+    }
+
+    #[test]
+    fn enumerates_areas() {
+        let grids = GridLocator::within_bounds((138.5, 35.2), (141.0, 36.1), GridPrecision::Square);
+        let grids: Vec<_> = grids.map(|g| g.to_string()).collect();
+        assert_eq!(grids, ["PM95", "QM05", "PM96", "QM06"]);
+
+        let grids = GridLocator::within_bounds((179.5, 0.5), (-179.5, 0.5), GridPrecision::Square);
+        let grids: Vec<_> = grids.map(|g| g.to_string()).collect();
+        assert_eq!(grids, ["RJ90", "AJ00"]);
+
+        let grids = GridLocator::within_radius((139.0, 35.5), 120.0, GridPrecision::Square);
+        let mut grids: Vec<_> = grids.map(|g| g.to_string()).collect();
+        grids.sort();
+        assert_eq!(grids, ["PM94", "PM95", "PM96"]);
+
+        let mut grids = GridLocator::within_radius((139.0, 35.5), 100.0, GridPrecision::Sub3);
+        assert_eq!(grids.next().map(|g| g.to_string().len()), Some(10));
+    }
+
+    #[test]
+    fn parses_multiple_grids() {
+        let grids = parse_grids("PM95, PM96,").unwrap();
+        assert_eq!(grids, ["PM95".parse().unwrap(), "PM96".parse().unwrap()]);
+        assert!(parse_grids("PM95,XX").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        assert!("SA00".parse::<GridLocator>().is_err());
+        assert!("PM95yy".parse::<GridLocator>().is_err());
+    }
+}
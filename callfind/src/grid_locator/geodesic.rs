@@ -0,0 +1,199 @@
+use std::f64::consts::PI;
+
+/// Kilometers per statute mile.
+const KM_PER_MILE: f64 = 1.609344;
+
+/// IUGG mean radius of the Earth in kilometers.
+const MEAN_RADIUS_KM: f64 = 6371.0088;
+
+/// Semi-major axis of WGS-84 ellipsoid in kilometers.
+const WGS84_A_KM: f64 = 6378.137;
+
+/// Flattening of WGS-84 ellipsoid.
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+/// Model of the Earth to compute paths on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EarthModel {
+    /// Sphere of the mean radius with haversine formula.
+    /// Errors are up to about 0.5% of distance.
+    #[default]
+    Spherical,
+
+    /// WGS-84 ellipsoid with Vincenty's inverse formula.
+    /// Errors are below 1 mm, but nearly antipodal points fall back to the spherical model.
+    Wgs84,
+}
+
+/// Great-circle (or geodesic) path between two points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPath {
+    /// Distance in kilometers.
+    pub distance_km: f64,
+
+    /// Bearing at the start point in degrees clockwise from true north.
+    pub initial_bearing: f64,
+
+    /// Bearing at the end point toward the start point in degrees.
+    pub reverse_bearing: f64,
+}
+
+impl GeoPath {
+    /// Computes path between longitude/latitude pairs in degrees.
+    pub fn between(from: (f64, f64), to: (f64, f64), model: EarthModel) -> GeoPath {
+        match model {
+            EarthModel::Spherical => spherical_path(from, to),
+            EarthModel::Wgs84 => {
+                vincenty_path(from, to).unwrap_or_else(|| spherical_path(from, to))
+            }
+        }
+    }
+
+    /// Distance in statute miles.
+    pub fn distance_miles(&self) -> f64 {
+        self.distance_km / KM_PER_MILE
+    }
+}
+
+fn spherical_path((lng1, lat1): (f64, f64), (lng2, lat2): (f64, f64)) -> GeoPath {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let delta_phi = phi2 - phi1;
+    let delta_lambda = (lng2 - lng1).to_radians();
+
+    let h = (delta_phi / 2.0).sin().powi(2)
+        + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+    let central_angle = 2.0 * h.sqrt().min(1.0).asin();
+
+    let bearing = |phi_a: f64, phi_b: f64, delta_lambda: f64| {
+        let y = delta_lambda.sin() * phi_b.cos();
+        let x = phi_a.cos() * phi_b.sin() - phi_a.sin() * phi_b.cos() * delta_lambda.cos();
+        normalize_degrees(y.atan2(x).to_degrees())
+    };
+
+    GeoPath {
+        distance_km: MEAN_RADIUS_KM * central_angle,
+        initial_bearing: bearing(phi1, phi2, delta_lambda),
+        reverse_bearing: bearing(phi2, phi1, -delta_lambda),
+    }
+}
+
+fn vincenty_path((lng1, lat1): (f64, f64), (lng2, lat2): (f64, f64)) -> Option<GeoPath> {
+    let b = WGS84_A_KM * (1.0 - WGS84_F);
+    let l = (lng2 - lng1).to_radians();
+    let u1 = ((1.0 - WGS84_F) * lat1.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * lat2.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            return Some(GeoPath {
+                distance_km: 0.0,
+                initial_bearing: 0.0,
+                reverse_bearing: 0.0,
+            });
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha.powi(2);
+        // equatorial lines have cos2_alpha = 0
+        let cos_2sigma_m = if cos2_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+        };
+        let c = WGS84_F / 16.0 * cos2_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos2_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+        if lambda.abs() > PI {
+            return None;
+        }
+        if (lambda - previous).abs() > 1e-12 {
+            continue;
+        }
+
+        let u_sq = cos2_alpha * (WGS84_A_KM.powi(2) - b.powi(2)) / b.powi(2);
+        let k1 = ((1.0 + u_sq).sqrt() - 1.0) / ((1.0 + u_sq).sqrt() + 1.0);
+        let big_a = (1.0 + k1.powi(2) / 4.0) / (1.0 - k1);
+        let big_b = k1 * (1.0 - 3.0 / 8.0 * k1.powi(2));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                        - big_b / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma.powi(2))
+                            * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let alpha1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let alpha2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+        return Some(GeoPath {
+            distance_km: b * big_a * (sigma - delta_sigma),
+            initial_bearing: normalize_degrees(alpha1.to_degrees()),
+            reverse_bearing: normalize_degrees(alpha2.to_degrees() + 180.0),
+        });
+    }
+
+    None
+}
+
+fn normalize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EarthModel, GeoPath};
+
+    const TOKYO: (f64, f64) = (139.767, 35.681);
+    const NEW_YORK: (f64, f64) = (-74.006, 40.713);
+
+    #[test]
+    fn computes_spherical_path() {
+        let path = GeoPath::between(TOKYO, NEW_YORK, EarthModel::Spherical);
+        assert!((path.distance_km - 10_850.0).abs() < 10.0, "{path:?}");
+        assert!((path.initial_bearing - 25.0).abs() < 1.0, "{path:?}");
+        assert!((path.reverse_bearing - 333.0).abs() < 1.0, "{path:?}");
+        assert!((path.distance_miles() - 6_742.0).abs() < 10.0, "{path:?}");
+    }
+
+    #[test]
+    fn computes_ellipsoidal_path() {
+        // Flinders Peak to Buninyong, Vincenty (1975)
+        let from = (
+            144.0 + 25.0 / 60.0 + 29.52440 / 3600.0,
+            -(37.0 + 57.0 / 60.0 + 3.72030 / 3600.0),
+        );
+        let to = (
+            143.0 + 55.0 / 60.0 + 35.38390 / 3600.0,
+            -(37.0 + 39.0 / 60.0 + 10.15610 / 3600.0),
+        );
+        let path = GeoPath::between(from, to, EarthModel::Wgs84);
+        assert!((path.distance_km - 54.972271).abs() < 1e-5, "{path:?}");
+        assert!((path.initial_bearing - 306.868159).abs() < 1e-5, "{path:?}");
+        assert!((path.reverse_bearing - 127.173633).abs() < 1e-5, "{path:?}");
+    }
+
+    #[test]
+    fn handles_degenerate_points() {
+        let path = GeoPath::between(TOKYO, TOKYO, EarthModel::Wgs84);
+        assert_eq!(path.distance_km, 0.0);
+
+        let path = GeoPath::between((0.0, 0.0), (179.9, 0.0), EarthModel::Wgs84);
+        assert!((path.distance_km - 20_004.0).abs() < 50.0, "{path:?}");
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     str::{FromStr, from_utf8_unchecked},
 };
 
-use crate::grid_locator::error::GridLocatorError;
+use crate::grid_locator::{error::GridLocatorError, precision::GridPrecision};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GridLocatorInner {
@@ -23,6 +23,17 @@ impl GridLocatorInner {
             GridLocatorInner::ToSub3(i) => i,
         }
     }
+
+    /// Takes leading pairs of indices.
+    pub fn truncated(&self, precision: GridPrecision) -> GridLocatorInner {
+        let i = self.indices();
+        match precision.characters().min(i.len()) {
+            4 => GridLocatorInner::ToSquare([i[0], i[1], i[2], i[3]]),
+            6 => GridLocatorInner::ToSub1([i[0], i[1], i[2], i[3], i[4], i[5]]),
+            8 => GridLocatorInner::ToSub2([i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]]),
+            _ => *self,
+        }
+    }
 }
 
 impl FromStr for GridLocatorInner {
@@ -43,7 +54,7 @@ impl FromStr for GridLocatorInner {
         let sub2 = (s.len() >= 8).then(|| (s[6].to_ascii_lowercase(), s[7].to_ascii_lowercase()));
         let sub3 = (s.len() >= 10).then(|| (s[8].to_ascii_lowercase(), s[9].to_ascii_lowercase()));
         let (fi_lng, fi_lat) = match field {
-            (lng @ b'A'..=b'Z', lat @ b'A'..=b'Z') => (lng - b'A', lat - b'A'),
+            (lng @ b'A'..=b'R', lat @ b'A'..=b'R') => (lng - b'A', lat - b'A'),
             _ => return Err(GridLocatorError::OutOfRange),
         };
         let (si_lng, si_lat) = match square {
@@ -54,7 +65,7 @@ impl FromStr for GridLocatorInner {
             None => {
                 return Ok(GridLocatorInner::ToSquare([fi_lng, fi_lat, si_lng, si_lat]));
             }
-            Some((lng @ b'a'..=b'z', lat @ b'a'..=b'z')) => (lng - b'a', lat - b'a'),
+            Some((lng @ b'a'..=b'x', lat @ b'a'..=b'x')) => (lng - b'a', lat - b'a'),
             _ => return Err(GridLocatorError::OutOfRange),
         };
         let (s2i_lng, s2i_lat) = match sub2 {
@@ -72,7 +83,7 @@ impl FromStr for GridLocatorInner {
                     fi_lng, fi_lat, si_lng, si_lat, s1i_lng, s1i_lat, s2i_lng, s2i_lat,
                 ]));
             }
-            Some((lng @ b'a'..=b'z', lat @ b'a'..=b'z')) => (lng - b'a', lat - b'a'),
+            Some((lng @ b'a'..=b'x', lat @ b'a'..=b'x')) => (lng - b'a', lat - b'a'),
             _ => return Err(GridLocatorError::OutOfRange),
         };
 
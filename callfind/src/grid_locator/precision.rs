@@ -0,0 +1,60 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use crate::grid_locator::error::GridLocatorError;
+
+/// Number of pairs in grid locator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GridPrecision {
+    /// 4 characters such as `PM95`.
+    Square,
+
+    /// 6 characters such as `PM95tq`.
+    Sub1,
+
+    /// 8 characters such as `PM95tq15`.
+    Sub2,
+
+    /// 10 characters such as `PM95tq15ax`.
+    Sub3,
+}
+
+impl GridPrecision {
+    pub const fn characters(self) -> usize {
+        match self {
+            GridPrecision::Square => 4,
+            GridPrecision::Sub1 => 6,
+            GridPrecision::Sub2 => 8,
+            GridPrecision::Sub3 => 10,
+        }
+    }
+
+    pub const fn from_characters(characters: usize) -> Option<GridPrecision> {
+        match characters {
+            4 => Some(GridPrecision::Square),
+            6 => Some(GridPrecision::Sub1),
+            8 => Some(GridPrecision::Sub2),
+            10 => Some(GridPrecision::Sub3),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for GridPrecision {
+    type Err = GridLocatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .ok()
+            .and_then(GridPrecision::from_characters)
+            .ok_or(GridLocatorError::InvalidLength)
+    }
+}
+
+impl Display for GridPrecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.characters())
+    }
+}
@@ -0,0 +1,161 @@
+mod data;
+mod jarl_area;
+mod prefecture;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::callsign::Callsign;
+
+pub use crate::japan::{
+    jarl_area::{
+        JarlArea, JarlAreaKind, find_jarl_areas_by_name, jarl_areas, lookup_jarl_area,
+        search_jarl_areas,
+    },
+    prefecture::{InvalidPrefecture, Prefecture, Region},
+};
+
+/// Kind of station which the callsign series is issued to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LicenseSeries {
+    Personal,
+
+    /// Three-letter suffix starting with `Y`, such as `JA1YAA`.
+    Club,
+
+    /// `8J` to `8N` series.
+    SpecialEvent,
+
+    /// Two-letter suffix starting with `V` or `W` in `JR` series, such as `JR1VA`.
+    Repeater,
+}
+
+/// Japanese callsign with its call area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JapaneseCallsign {
+    /// Call area in operation, which follows `/1` form override.
+    pub area: u8,
+    pub region: Region,
+    pub series: LicenseSeries,
+}
+
+impl JapaneseCallsign {
+    /// Prefectures in the call area.
+    pub fn prefectures(&self) -> &'static [Prefecture] {
+        self.region.prefectures()
+    }
+}
+
+impl Display for LicenseSeries {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LicenseSeries::Personal => write!(f, "personal"),
+            LicenseSeries::Club => write!(f, "club"),
+            LicenseSeries::SpecialEvent => write!(f, "special event"),
+            LicenseSeries::Repeater => write!(f, "repeater"),
+        }
+    }
+}
+
+/// Returns the call area digit if the text starts with a Japanese prefix,
+/// which is one of `JA`-`JS`, `7J`-`7N` and `8J`-`8N` followed by a digit.
+fn japanese_area(text: &str) -> Option<u8> {
+    match text.as_bytes() {
+        [b'J', b'A'..=b'S', area @ b'0'..=b'9', ..]
+        | [b'7' | b'8', b'J'..=b'N', area @ b'0'..=b'9', ..] => Some(area - b'0'),
+        _ => None,
+    }
+}
+
+/// Classifies a callsign operated in Japan, or returns `None` for other calls.
+/// The series follows the base callsign, so `JA1YAA/JD1` stays a club station,
+/// while a foreign callsign with a Japanese prefix such as `W1AW/JA1` is treated as personal.
+pub fn classify_japanese(callsign: &str) -> Option<JapaneseCallsign> {
+    let parsed: Callsign = callsign.parse().ok()?;
+    let base = parsed.base();
+    let base_area = japanese_area(base);
+    let area = match parsed.prefix_override() {
+        Some(prefix) => japanese_area(prefix)?,
+        None => parsed.area_override().or(base_area)?,
+    };
+    let series = match base_area {
+        Some(_) => japanese_series(base),
+        None => LicenseSeries::Personal,
+    };
+
+    Some(JapaneseCallsign {
+        area,
+        region: Region::from_area(area)?,
+        series,
+    })
+}
+
+/// License series of a callsign starting with a Japanese prefix.
+fn japanese_series(base: &str) -> LicenseSeries {
+    let suffix = &base[3..];
+    match base.as_bytes() {
+        [b'8', ..] => LicenseSeries::SpecialEvent,
+        [b'J', b'R', _, b'V' | b'W', _] => LicenseSeries::Repeater,
+        _ if suffix.len() == 3 && suffix.starts_with('Y') => LicenseSeries::Club,
+        _ => LicenseSeries::Personal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LicenseSeries, Prefecture, Region, classify_japanese};
+
+    #[test]
+    fn classifies_series() {
+        let call = classify_japanese("JA1ZZZ").expect("Japanese call");
+        assert_eq!((call.area, call.region), (1, Region::Kanto));
+        assert_eq!(call.series, LicenseSeries::Personal);
+        assert!(call.prefectures().contains(&Prefecture::Yamanashi));
+
+        let series = |c| classify_japanese(c).map(|j| j.series);
+        assert_eq!(series("JA3YAA"), Some(LicenseSeries::Club));
+        assert_eq!(series("8J1ITU"), Some(LicenseSeries::SpecialEvent));
+        assert_eq!(series("JR1VA"), Some(LicenseSeries::Repeater));
+        assert_eq!(series("7K1ABC"), Some(LicenseSeries::Personal));
+        assert_eq!(series("W1AW"), None);
+    }
+
+    #[test]
+    fn follows_area_override() {
+        let call = classify_japanese("JA1ZZZ/0").expect("Japanese call");
+        assert_eq!((call.area, call.region), (0, Region::Shinetsu));
+        assert_eq!(
+            call.prefectures(),
+            &[Prefecture::Niigata, Prefecture::Nagano]
+        );
+
+        let call = classify_japanese("W1AW/JA6").expect("operated in Japan");
+        assert_eq!(call.region, Region::Kyushu);
+        assert_eq!(classify_japanese("KH2/JA1ZZZ"), None);
+
+        let call = classify_japanese("JA1YAA/JD1").expect("operated in Japan");
+        assert_eq!((call.area, call.series), (1, LicenseSeries::Club));
+        let call = classify_japanese("JR1VA/JA6").expect("operated in Japan");
+        assert_eq!((call.area, call.series), (6, LicenseSeries::Repeater));
+        let call = classify_japanese("W1AW/JA6").expect("operated in Japan");
+        assert_eq!(call.series, LicenseSeries::Personal);
+    }
+
+    #[test]
+    fn maps_prefecture_numbers() {
+        assert_eq!(Prefecture::from_number(10), Some(Prefecture::Tokyo));
+        assert_eq!(Prefecture::Okinawa.number(), 47);
+        assert_eq!(Prefecture::from_number(48), Some(Prefecture::Ogasawara));
+        assert_eq!(Prefecture::from_number(49), None);
+        assert_eq!(Prefecture::from_number(0), None);
+        assert_eq!("Tokyo".parse::<Prefecture>().ok(), Some(Prefecture::Tokyo));
+        assert_eq!("大阪府".parse::<Prefecture>().ok(), Some(Prefecture::Osaka));
+        for region in Region::ALL {
+            assert_eq!(Region::from_area(region.area()), Some(region));
+        }
+        assert!(
+            Prefecture::ALL
+                .iter()
+                .all(|p| p.region().prefectures().contains(p))
+        );
+    }
+}
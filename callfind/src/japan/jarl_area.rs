@@ -0,0 +1,211 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use time::Date;
+
+use crate::japan::{Prefecture, data::JARL_AREAS};
+
+/// Kind of JARL award area numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JarlAreaKind {
+    /// Prefecture number such as `10` for Tokyo.
+    Prefecture,
+
+    /// Hokkaido subprefecture number such as `106` for Ishikari.
+    Subprefecture,
+
+    /// JCC, city number such as `0101` for Sapporo.
+    City,
+
+    /// JCG, county (gun) number such as `10001`.
+    Gun,
+
+    /// Ward (ku) number such as `010101`, which is JCC followed by two digits.
+    Ward,
+}
+
+/// An entry of JCC/JCG and other JARL area tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JarlArea {
+    pub kind: JarlAreaKind,
+    pub code: &'static str,
+    pub name_ja: &'static str,
+
+    /// Name in romaji.
+    pub name: &'static str,
+    pub prefecture: Prefecture,
+
+    pub valid_from: Option<Date>,
+    pub valid_until: Option<Date>,
+
+    /// Whether the area has been abolished or merged.
+    pub deleted: bool,
+}
+
+impl JarlArea {
+    /// Whether the area existed on the date.
+    pub fn is_valid_on(&self, date: Date) -> bool {
+        self.valid_from.is_none_or(|from| from <= date)
+            && self.valid_until.is_none_or(|until| date <= until)
+    }
+}
+
+impl Display for JarlAreaKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            JarlAreaKind::Prefecture => write!(f, "prefecture"),
+            JarlAreaKind::Subprefecture => write!(f, "subprefecture"),
+            JarlAreaKind::City => write!(f, "JCC"),
+            JarlAreaKind::Gun => write!(f, "JCG"),
+            JarlAreaKind::Ward => write!(f, "ward"),
+        }
+    }
+}
+
+/// All entries, sorted by kind and code.
+/// Prefectures and subprefectures are complete, while JCC, JCG and ward numbers are partial.
+pub fn jarl_areas() -> &'static [JarlArea] {
+    JARL_AREAS
+}
+
+/// Looks up an entry by its code.
+pub fn lookup_jarl_area(kind: JarlAreaKind, code: &str) -> Option<&'static JarlArea> {
+    let i = JARL_AREAS
+        .binary_search_by(|a| (a.kind, a.code).cmp(&(kind, code)))
+        .ok()?;
+    Some(&JARL_AREAS[i])
+}
+
+/// Finds entries whose Japanese or romaji name is exactly the name,
+/// ignoring full-width/half-width differences and case.
+pub fn find_jarl_areas_by_name(name: &str) -> Vec<&'static JarlArea> {
+    let name = normalize_width(name);
+    JARL_AREAS
+        .iter()
+        .filter(|a| normalize_width(a.name_ja) == name || normalize_width(a.name) == name)
+        .collect()
+}
+
+/// Finds entries whose Japanese or romaji name contains the query,
+/// ignoring full-width/half-width differences and case.
+pub fn search_jarl_areas(query: &str) -> Vec<&'static JarlArea> {
+    let query = normalize_width(query);
+    JARL_AREAS
+        .iter()
+        .filter(|a| {
+            normalize_width(a.name_ja).contains(&query) || normalize_width(a.name).contains(&query)
+        })
+        .collect()
+}
+
+/// Half-width katakana from U+FF66 to U+FF9D.
+const HALF_WIDTH_KATAKANA: &str = "ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+/// Converts full-width ASCII into half-width and half-width katakana into full-width,
+/// then lowercases ASCII letters.
+fn normalize_width(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        match c {
+            '\u{3000}' => normalized.push(' '),
+            '\u{FF01}'..='\u{FF5E}' => {
+                let ascii = char::from_u32(c as u32 - 0xFEE0).expect("must be ASCII");
+                normalized.push(ascii.to_ascii_lowercase());
+            }
+            '\u{FF61}' => normalized.push('。'),
+            '\u{FF62}' => normalized.push('「'),
+            '\u{FF63}' => normalized.push('」'),
+            '\u{FF64}' => normalized.push('、'),
+            '\u{FF65}' => normalized.push('・'),
+            '\u{FF66}'..='\u{FF9D}' => {
+                let index = (c as u32 - 0xFF66) as usize;
+                normalized.push(
+                    HALF_WIDTH_KATAKANA
+                        .chars()
+                        .nth(index)
+                        .expect("must be in the table"),
+                );
+            }
+            // voiced and semi-voiced sound marks combine with the preceding kana
+            '\u{FF9E}' | '\u{FF9F}' => {
+                let semi = c == '\u{FF9F}';
+                let combined = match normalized.pop() {
+                    Some('ウ') if !semi => Some('ヴ'),
+                    Some(k) if !semi && "カキクケコサシスセソタチツテト".contains(k) => {
+                        char::from_u32(k as u32 + 1)
+                    }
+                    Some(k) if "ハヒフヘホ".contains(k) => {
+                        char::from_u32(k as u32 + if semi { 2 } else { 1 })
+                    }
+                    Some(k) => {
+                        normalized.push(k);
+                        None
+                    }
+                    None => None,
+                };
+                match combined {
+                    Some(k) => normalized.push(k),
+                    None => normalized.push(if semi { '゜' } else { '゛' }),
+                }
+            }
+            c => normalized.push(c.to_ascii_lowercase()),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::{
+        JarlAreaKind, find_jarl_areas_by_name, lookup_jarl_area, normalize_width, search_jarl_areas,
+    };
+    use crate::japan::Prefecture;
+
+    #[test]
+    fn looks_up_codes() {
+        let sapporo = lookup_jarl_area(JarlAreaKind::City, "0101").expect("must exist");
+        assert_eq!(sapporo.name_ja, "札幌市");
+        assert_eq!(sapporo.prefecture, Prefecture::Hokkaido);
+
+        let tokyo = lookup_jarl_area(JarlAreaKind::Prefecture, "10").expect("must exist");
+        assert_eq!(tokyo.name, "Tokyo");
+        let ogasawara = lookup_jarl_area(JarlAreaKind::Prefecture, "48").expect("must exist");
+        assert_eq!(ogasawara.prefecture, Prefecture::Ogasawara);
+        assert_eq!(lookup_jarl_area(JarlAreaKind::City, "10"), None);
+    }
+
+    #[test]
+    fn finds_by_name() {
+        let found = find_jarl_areas_by_name("ＳＡＰＰＯＲＯ");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].code, "0101");
+
+        let okhotsk = find_jarl_areas_by_name("ｵﾎｰﾂｸ");
+        assert_eq!(okhotsk.first().map(|a| a.code), Some("104"));
+
+        assert!(search_jarl_areas("区").len() > 10);
+    }
+
+    #[test]
+    fn tracks_validity() {
+        let urawa = lookup_jarl_area(JarlAreaKind::City, "1304").expect("must exist");
+        assert!(urawa.deleted);
+        assert!(urawa.is_valid_on(date!(2001 - 04 - 30)));
+        assert!(!urawa.is_valid_on(date!(2001 - 05 - 01)));
+
+        let kiyota = lookup_jarl_area(JarlAreaKind::Ward, "010110").expect("must exist");
+        assert!(!kiyota.is_valid_on(date!(1997 - 01 - 01)));
+
+        let teine = lookup_jarl_area(JarlAreaKind::Ward, "010109").expect("must exist");
+        assert!(!teine.is_valid_on(date!(1989 - 11 - 05)));
+        assert!(teine.is_valid_on(date!(1989 - 11 - 06)));
+    }
+
+    #[test]
+    fn normalizes_width() {
+        assert_eq!(normalize_width("ｻｯﾎﾟﾛ　ｶﾞｲﾄﾞ"), "サッポロ ガイド");
+        assert_eq!(normalize_width("ｳﾞｧ"), "ヴァ");
+        assert_eq!(normalize_width("Ｊａ１"), "ja1");
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+/// Prefecture, numbered as in JARL awards.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum Prefecture {
+    Hokkaido = 1,
+    Aomori,
+    Iwate,
+    Akita,
+    Yamagata,
+    Miyagi,
+    Fukushima,
+    Niigata,
+    Nagano,
+    Tokyo,
+    Kanagawa,
+    Chiba,
+    Saitama,
+    Ibaraki,
+    Tochigi,
+    Gunma,
+    Yamanashi,
+    Shizuoka,
+    Gifu,
+    Aichi,
+    Mie,
+    Kyoto,
+    Shiga,
+    Nara,
+    Osaka,
+    Wakayama,
+    Hyogo,
+    Toyama,
+    Fukui,
+    Ishikawa,
+    Okayama,
+    Shimane,
+    Yamaguchi,
+    Tottori,
+    Hiroshima,
+    Kagawa,
+    Tokushima,
+    Ehime,
+    Kochi,
+    Fukuoka,
+    Saga,
+    Nagasaki,
+    Kumamoto,
+    Oita,
+    Miyazaki,
+    Kagoshima,
+    Okinawa,
+
+    /// Ogasawara Islands, part of Tokyo but numbered separately by JARL.
+    Ogasawara,
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("invalid prefecture")]
+pub struct InvalidPrefecture;
+
+/// Region which a call area number covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Region {
+    Kanto,
+    Tokai,
+    Kinki,
+    Chugoku,
+    Shikoku,
+    Kyushu,
+    Tohoku,
+    Hokkaido,
+    Hokuriku,
+    Shinetsu,
+}
+
+impl Prefecture {
+    pub const ALL: [Prefecture; 48] = [
+        Prefecture::Hokkaido,
+        Prefecture::Aomori,
+        Prefecture::Iwate,
+        Prefecture::Akita,
+        Prefecture::Yamagata,
+        Prefecture::Miyagi,
+        Prefecture::Fukushima,
+        Prefecture::Niigata,
+        Prefecture::Nagano,
+        Prefecture::Tokyo,
+        Prefecture::Kanagawa,
+        Prefecture::Chiba,
+        Prefecture::Saitama,
+        Prefecture::Ibaraki,
+        Prefecture::Tochigi,
+        Prefecture::Gunma,
+        Prefecture::Yamanashi,
+        Prefecture::Shizuoka,
+        Prefecture::Gifu,
+        Prefecture::Aichi,
+        Prefecture::Mie,
+        Prefecture::Kyoto,
+        Prefecture::Shiga,
+        Prefecture::Nara,
+        Prefecture::Osaka,
+        Prefecture::Wakayama,
+        Prefecture::Hyogo,
+        Prefecture::Toyama,
+        Prefecture::Fukui,
+        Prefecture::Ishikawa,
+        Prefecture::Okayama,
+        Prefecture::Shimane,
+        Prefecture::Yamaguchi,
+        Prefecture::Tottori,
+        Prefecture::Hiroshima,
+        Prefecture::Kagawa,
+        Prefecture::Tokushima,
+        Prefecture::Ehime,
+        Prefecture::Kochi,
+        Prefecture::Fukuoka,
+        Prefecture::Saga,
+        Prefecture::Nagasaki,
+        Prefecture::Kumamoto,
+        Prefecture::Oita,
+        Prefecture::Miyazaki,
+        Prefecture::Kagoshima,
+        Prefecture::Okinawa,
+        Prefecture::Ogasawara,
+    ];
+
+    /// JARL prefecture number, which is the leading digits of JCC/JCG codes.
+    pub fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_number(number: u8) -> Option<Prefecture> {
+        Prefecture::ALL
+            .get(usize::from(number).checked_sub(1)?)
+            .copied()
+    }
+
+    pub fn name_ja(&self) -> &'static str {
+        match self {
+            Prefecture::Hokkaido => "北海道",
+            Prefecture::Aomori => "青森県",
+            Prefecture::Iwate => "岩手県",
+            Prefecture::Akita => "秋田県",
+            Prefecture::Yamagata => "山形県",
+            Prefecture::Miyagi => "宮城県",
+            Prefecture::Fukushima => "福島県",
+            Prefecture::Niigata => "新潟県",
+            Prefecture::Nagano => "長野県",
+            Prefecture::Tokyo => "東京都",
+            Prefecture::Kanagawa => "神奈川県",
+            Prefecture::Chiba => "千葉県",
+            Prefecture::Saitama => "埼玉県",
+            Prefecture::Ibaraki => "茨城県",
+            Prefecture::Tochigi => "栃木県",
+            Prefecture::Gunma => "群馬県",
+            Prefecture::Yamanashi => "山梨県",
+            Prefecture::Shizuoka => "静岡県",
+            Prefecture::Gifu => "岐阜県",
+            Prefecture::Aichi => "愛知県",
+            Prefecture::Mie => "三重県",
+            Prefecture::Kyoto => "京都府",
+            Prefecture::Shiga => "滋賀県",
+            Prefecture::Nara => "奈良県",
+            Prefecture::Osaka => "大阪府",
+            Prefecture::Wakayama => "和歌山県",
+            Prefecture::Hyogo => "兵庫県",
+            Prefecture::Toyama => "富山県",
+            Prefecture::Fukui => "福井県",
+            Prefecture::Ishikawa => "石川県",
+            Prefecture::Okayama => "岡山県",
+            Prefecture::Shimane => "島根県",
+            Prefecture::Yamaguchi => "山口県",
+            Prefecture::Tottori => "鳥取県",
+            Prefecture::Hiroshima => "広島県",
+            Prefecture::Kagawa => "香川県",
+            Prefecture::Tokushima => "徳島県",
+            Prefecture::Ehime => "愛媛県",
+            Prefecture::Kochi => "高知県",
+            Prefecture::Fukuoka => "福岡県",
+            Prefecture::Saga => "佐賀県",
+            Prefecture::Nagasaki => "長崎県",
+            Prefecture::Kumamoto => "熊本県",
+            Prefecture::Oita => "大分県",
+            Prefecture::Miyazaki => "宮崎県",
+            Prefecture::Kagoshima => "鹿児島県",
+            Prefecture::Okinawa => "沖縄県",
+            Prefecture::Ogasawara => "小笠原",
+        }
+    }
+
+    pub fn region(&self) -> Region {
+        Region::ALL
+            .into_iter()
+            .find(|r| r.prefectures().contains(self))
+            .expect("every prefecture belongs to a region")
+    }
+}
+
+impl FromStr for Prefecture {
+    type Err = InvalidPrefecture;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse() {
+            return Prefecture::from_number(number).ok_or(InvalidPrefecture);
+        }
+        Prefecture::ALL
+            .into_iter()
+            .find(|p| p.to_string().eq_ignore_ascii_case(s) || p.name_ja() == s)
+            .ok_or(InvalidPrefecture)
+    }
+}
+
+impl Display for Prefecture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Prefecture::Hokkaido => "Hokkaido",
+            Prefecture::Aomori => "Aomori",
+            Prefecture::Iwate => "Iwate",
+            Prefecture::Akita => "Akita",
+            Prefecture::Yamagata => "Yamagata",
+            Prefecture::Miyagi => "Miyagi",
+            Prefecture::Fukushima => "Fukushima",
+            Prefecture::Niigata => "Niigata",
+            Prefecture::Nagano => "Nagano",
+            Prefecture::Tokyo => "Tokyo",
+            Prefecture::Kanagawa => "Kanagawa",
+            Prefecture::Chiba => "Chiba",
+            Prefecture::Saitama => "Saitama",
+            Prefecture::Ibaraki => "Ibaraki",
+            Prefecture::Tochigi => "Tochigi",
+            Prefecture::Gunma => "Gunma",
+            Prefecture::Yamanashi => "Yamanashi",
+            Prefecture::Shizuoka => "Shizuoka",
+            Prefecture::Gifu => "Gifu",
+            Prefecture::Aichi => "Aichi",
+            Prefecture::Mie => "Mie",
+            Prefecture::Kyoto => "Kyoto",
+            Prefecture::Shiga => "Shiga",
+            Prefecture::Nara => "Nara",
+            Prefecture::Osaka => "Osaka",
+            Prefecture::Wakayama => "Wakayama",
+            Prefecture::Hyogo => "Hyogo",
+            Prefecture::Toyama => "Toyama",
+            Prefecture::Fukui => "Fukui",
+            Prefecture::Ishikawa => "Ishikawa",
+            Prefecture::Okayama => "Okayama",
+            Prefecture::Shimane => "Shimane",
+            Prefecture::Yamaguchi => "Yamaguchi",
+            Prefecture::Tottori => "Tottori",
+            Prefecture::Hiroshima => "Hiroshima",
+            Prefecture::Kagawa => "Kagawa",
+            Prefecture::Tokushima => "Tokushima",
+            Prefecture::Ehime => "Ehime",
+            Prefecture::Kochi => "Kochi",
+            Prefecture::Fukuoka => "Fukuoka",
+            Prefecture::Saga => "Saga",
+            Prefecture::Nagasaki => "Nagasaki",
+            Prefecture::Kumamoto => "Kumamoto",
+            Prefecture::Oita => "Oita",
+            Prefecture::Miyazaki => "Miyazaki",
+            Prefecture::Kagoshima => "Kagoshima",
+            Prefecture::Okinawa => "Okinawa",
+            Prefecture::Ogasawara => "Ogasawara",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Region {
+    pub const ALL: [Region; 10] = [
+        Region::Kanto,
+        Region::Tokai,
+        Region::Kinki,
+        Region::Chugoku,
+        Region::Shikoku,
+        Region::Kyushu,
+        Region::Tohoku,
+        Region::Hokkaido,
+        Region::Hokuriku,
+        Region::Shinetsu,
+    ];
+
+    pub fn from_area(area: u8) -> Option<Region> {
+        match area {
+            0 => Some(Region::Shinetsu),
+            1..=9 => Some(Region::ALL[usize::from(area) - 1]),
+            _ => None,
+        }
+    }
+
+    /// Call area number, such as 1 for Kanto and 0 for Shinetsu.
+    pub fn area(&self) -> u8 {
+        match self {
+            Region::Shinetsu => 0,
+            other => *other as u8 + 1,
+        }
+    }
+
+    pub fn prefectures(&self) -> &'static [Prefecture] {
+        match self {
+            Region::Kanto => &[
+                Prefecture::Tokyo,
+                Prefecture::Kanagawa,
+                Prefecture::Chiba,
+                Prefecture::Saitama,
+                Prefecture::Ibaraki,
+                Prefecture::Tochigi,
+                Prefecture::Gunma,
+                Prefecture::Yamanashi,
+                Prefecture::Ogasawara,
+            ],
+            Region::Tokai => &[
+                Prefecture::Shizuoka,
+                Prefecture::Gifu,
+                Prefecture::Aichi,
+                Prefecture::Mie,
+            ],
+            Region::Kinki => &[
+                Prefecture::Kyoto,
+                Prefecture::Shiga,
+                Prefecture::Nara,
+                Prefecture::Osaka,
+                Prefecture::Wakayama,
+                Prefecture::Hyogo,
+            ],
+            Region::Chugoku => &[
+                Prefecture::Okayama,
+                Prefecture::Shimane,
+                Prefecture::Yamaguchi,
+                Prefecture::Tottori,
+                Prefecture::Hiroshima,
+            ],
+            Region::Shikoku => &[
+                Prefecture::Kagawa,
+                Prefecture::Tokushima,
+                Prefecture::Ehime,
+                Prefecture::Kochi,
+            ],
+            Region::Kyushu => &[
+                Prefecture::Fukuoka,
+                Prefecture::Saga,
+                Prefecture::Nagasaki,
+                Prefecture::Kumamoto,
+                Prefecture::Oita,
+                Prefecture::Miyazaki,
+                Prefecture::Kagoshima,
+                Prefecture::Okinawa,
+            ],
+            Region::Tohoku => &[
+                Prefecture::Aomori,
+                Prefecture::Iwate,
+                Prefecture::Akita,
+                Prefecture::Yamagata,
+                Prefecture::Miyagi,
+                Prefecture::Fukushima,
+            ],
+            Region::Hokkaido => &[Prefecture::Hokkaido],
+            Region::Hokuriku => &[Prefecture::Toyama, Prefecture::Fukui, Prefecture::Ishikawa],
+            Region::Shinetsu => &[Prefecture::Niigata, Prefecture::Nagano],
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Region::Kanto => write!(f, "Kanto"),
+            Region::Tokai => write!(f, "Tokai"),
+            Region::Kinki => write!(f, "Kinki"),
+            Region::Chugoku => write!(f, "Chugoku"),
+            Region::Shikoku => write!(f, "Shikoku"),
+            Region::Kyushu => write!(f, "Kyushu"),
+            Region::Tohoku => write!(f, "Tohoku"),
+            Region::Hokkaido => write!(f, "Hokkaido"),
+            Region::Hokuriku => write!(f, "Hokuriku"),
+            Region::Shinetsu => write!(f, "Shinetsu"),
+        }
+    }
+}
@@ -1,2 +1,6 @@
 pub mod callsign;
+pub mod dxcc;
 pub mod grid_locator;
+pub mod japan;
+pub mod location;
+pub mod scp;
@@ -0,0 +1,226 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+use crate::grid_locator::{GridLocator, GridLocatorError, GridPrecision};
+
+/// Resolution of ADIF Location, thousandths of a minute per degree.
+const UNITS_PER_DEGREE: f64 = 60_000.0;
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum LocationError {
+    #[error("invalid format")]
+    InvalidFormat,
+
+    #[error("out of range")]
+    OutOfRange,
+}
+
+/// Point on the Earth in ADIF Location resolution.
+/// Displayed as latitude and longitude such as `N035 41.022 E139 46.020`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Location {
+    /// In thousandths of a minute, north positive.
+    latitude: i32,
+
+    /// In thousandths of a minute, east positive.
+    longitude: i32,
+}
+
+impl Location {
+    /// Constructs from longitude and latitude in degrees, rounding to ADIF resolution.
+    pub fn from_lnglat(longitude: f64, latitude: f64) -> Result<Location, LocationError> {
+        if !(-180.0..=180.0).contains(&longitude) || !(-90.0..=90.0).contains(&latitude) {
+            return Err(LocationError::OutOfRange);
+        }
+        Ok(Location {
+            latitude: (latitude * UNITS_PER_DEGREE).round() as i32,
+            longitude: (longitude * UNITS_PER_DEGREE).round() as i32,
+        })
+    }
+
+    /// Parses ADIF `LAT` and `LON` values such as `N035 41.022` and `E139 46.020`.
+    pub fn from_adif(latitude: &str, longitude: &str) -> Result<Location, LocationError> {
+        Ok(Location {
+            latitude: parse_coordinate(latitude, (b'N', b'S'), 90)?,
+            longitude: parse_coordinate(longitude, (b'E', b'W'), 180)?,
+        })
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude as f64 / UNITS_PER_DEGREE
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude as f64 / UNITS_PER_DEGREE
+    }
+
+    pub fn lnglat(&self) -> (f64, f64) {
+        (self.longitude(), self.latitude())
+    }
+
+    /// Formats as ADIF `LAT` value.
+    pub fn adif_latitude(&self) -> String {
+        format_coordinate(self.latitude, (b'N', b'S'))
+    }
+
+    /// Formats as ADIF `LON` value.
+    pub fn adif_longitude(&self) -> String {
+        format_coordinate(self.longitude, (b'E', b'W'))
+    }
+
+    /// Calculates grid locator containing this point.
+    /// Points on the east edge and the north pole belong to the opposite edges.
+    pub fn grid(&self, precision: GridPrecision) -> Result<GridLocator, GridLocatorError> {
+        let longitude = (self.longitude() + 180.0).rem_euclid(360.0) - 180.0;
+        let latitude = self.latitude().min(90.0 - 1e-9);
+        GridLocator::from_lnglat(longitude, latitude, precision)
+    }
+}
+
+impl From<GridLocator> for Location {
+    /// Takes the center of the grid.
+    fn from(grid: GridLocator) -> Location {
+        let (longitude, latitude) = grid.center();
+        Location::from_lnglat(longitude, latitude).expect("center must be in range")
+    }
+}
+
+impl FromStr for Location {
+    type Err = LocationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((latitude, longitude)) = s.get(..11).zip(s.get(11..)) else {
+            return Err(LocationError::InvalidFormat);
+        };
+        Location::from_adif(latitude, longitude.trim_start())
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {}", self.adif_latitude(), self.adif_longitude())
+    }
+}
+
+/// Parses ADIF `LAT` value such as `N035 41.022` into degrees.
+pub fn parse_adif_latitude(s: &str) -> Result<f64, LocationError> {
+    Ok(parse_coordinate(s, (b'N', b'S'), 90)? as f64 / UNITS_PER_DEGREE)
+}
+
+/// Parses ADIF `LON` value such as `E139 46.020` into degrees.
+pub fn parse_adif_longitude(s: &str) -> Result<f64, LocationError> {
+    Ok(parse_coordinate(s, (b'E', b'W'), 180)? as f64 / UNITS_PER_DEGREE)
+}
+
+/// Parses `XDDD MM.MMM` into thousandths of a minute.
+fn parse_coordinate(
+    s: &str,
+    (positive, negative): (u8, u8),
+    max_degrees: i32,
+) -> Result<i32, LocationError> {
+    let bytes = s.as_bytes();
+    let well_formed = bytes.len() == 11
+        && bytes[4] == b' '
+        && bytes[7] == b'.'
+        && [1, 2, 3, 5, 6, 8, 9, 10]
+            .iter()
+            .all(|&i| bytes[i].is_ascii_digit());
+    if !well_formed {
+        return Err(LocationError::InvalidFormat);
+    }
+
+    let sign = match bytes[0].to_ascii_uppercase() {
+        h if h == positive => 1,
+        h if h == negative => -1,
+        _ => return Err(LocationError::InvalidFormat),
+    };
+    let digits = |range: &[usize]| {
+        range
+            .iter()
+            .fold(0, |v, &i| v * 10 + (bytes[i] - b'0') as i32)
+    };
+    let degrees = digits(&[1, 2, 3]);
+    let thousandths = digits(&[5, 6, 8, 9, 10]);
+    if thousandths >= 60_000 || degrees * 60_000 + thousandths > max_degrees * 60_000 {
+        return Err(LocationError::OutOfRange);
+    }
+
+    Ok(sign * (degrees * 60_000 + thousandths))
+}
+
+fn format_coordinate(value: i32, (positive, negative): (u8, u8)) -> String {
+    let hemisphere = if value < 0 { negative } else { positive } as char;
+    let value = value.unsigned_abs();
+    let (degrees, thousandths) = (value / 60_000, value % 60_000);
+    format!(
+        "{hemisphere}{degrees:03} {:02}.{:03}",
+        thousandths / 1000,
+        thousandths % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Location, LocationError};
+    use crate::grid_locator::{GridLocator, GridPrecision};
+
+    #[test]
+    fn parses_adif_location() {
+        let location = Location::from_adif("N035 41.022", "E139 46.020").unwrap();
+        assert!((location.latitude() - 35.6837).abs() < 1e-9);
+        assert!((location.longitude() - 139.767).abs() < 1e-9);
+
+        let location = Location::from_adif("s033 51.000", "W070 40.500").unwrap();
+        assert!((location.latitude() + 33.85).abs() < 1e-9);
+        assert!((location.longitude() + 70.675).abs() < 1e-9);
+
+        assert_eq!(
+            Location::from_adif("N35 41.022", "E139 46.020"),
+            Err(LocationError::InvalidFormat)
+        );
+        assert_eq!(
+            Location::from_adif("E035 41.022", "E139 46.020"),
+            Err(LocationError::InvalidFormat)
+        );
+        assert_eq!(
+            Location::from_adif("N091 00.000", "E139 46.020"),
+            Err(LocationError::OutOfRange)
+        );
+        assert_eq!(
+            Location::from_adif("N035 60.000", "E139 46.020"),
+            Err(LocationError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn formats_adif_location() {
+        let location = Location::from_lnglat(-0.5, -33.85).unwrap();
+        assert_eq!(location.adif_latitude(), "S033 51.000");
+        assert_eq!(location.adif_longitude(), "W000 30.000");
+
+        let text = "N035 41.022 E139 46.020";
+        let location: Location = text.parse().unwrap();
+        assert_eq!(location.to_string(), text);
+    }
+
+    #[test]
+    fn converts_grid_locator() {
+        let location = Location::from_adif("N035 41.022", "E139 46.020").unwrap();
+        let grid = location.grid(GridPrecision::Sub1).unwrap();
+        assert_eq!(grid.to_string(), "PM95vq");
+
+        let grid: GridLocator = "PM95".parse().unwrap();
+        assert_eq!(Location::from(grid).to_string(), "N035 30.000 E139 00.000");
+
+        let east_edge = Location::from_lnglat(180.0, 90.0).unwrap();
+        assert_eq!(
+            east_edge.grid(GridPrecision::Square).unwrap().to_string(),
+            "AR09"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::HashMap, fs::read_to_string, io::Error as IoError, path::Path};
+
+use thiserror::Error as ThisError;
+
+use crate::callsign::edit_distance;
+
+#[derive(Debug, ThisError)]
+pub enum ScpError {
+    #[error("failed to read callsign list: {0}")]
+    Io(#[from] IoError),
+}
+
+/// Callsign database of Super Check Partial, loaded from `MASTER.SCP` or a plain call list.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScpDatabase {
+    /// Sorted and deduplicated in uppercase.
+    calls: Vec<String>,
+
+    /// Indices of calls containing each trigram, in ascending order.
+    trigrams: HashMap<[u8; 3], Vec<u32>>,
+}
+
+impl ScpDatabase {
+    pub fn load(path: impl AsRef<Path>) -> Result<ScpDatabase, ScpError> {
+        Ok(ScpDatabase::parse(&read_to_string(path)?))
+    }
+
+    /// Parses one callsign per line, taking the first column.
+    /// Empty lines and comment lines starting with `#` are ignored.
+    pub fn parse(text: &str) -> ScpDatabase {
+        let calls = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.starts_with('#'))
+            .filter_map(|l| l.split_whitespace().next());
+        ScpDatabase::from_calls(calls)
+    }
+
+    pub fn from_calls<'a>(calls: impl IntoIterator<Item = &'a str>) -> ScpDatabase {
+        let mut calls: Vec<_> = calls.into_iter().map(|c| c.to_ascii_uppercase()).collect();
+        calls.sort();
+        calls.dedup();
+
+        let mut trigrams: HashMap<_, Vec<_>> = HashMap::new();
+        for (i, call) in calls.iter().enumerate() {
+            for trigram in call.as_bytes().windows(3) {
+                let postings = trigrams
+                    .entry([trigram[0], trigram[1], trigram[2]])
+                    .or_default();
+                if postings.last() != Some(&(i as u32)) {
+                    postings.push(i as u32);
+                }
+            }
+        }
+
+        ScpDatabase { calls, trigrams }
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    pub fn calls(&self) -> &[String] {
+        &self.calls
+    }
+
+    pub fn contains(&self, call: &str) -> bool {
+        let upper = call.trim().to_ascii_uppercase();
+        self.calls.binary_search(&upper).is_ok()
+    }
+
+    /// Finds calls containing the pattern, where `?` matches any character
+    /// and `*` matches any sequence, such as `JL1H?S` or `*HIS`.
+    pub fn partial(&self, pattern: &str) -> Vec<&str> {
+        let pattern = pattern.trim().to_ascii_uppercase();
+        let wrapped = format!("*{pattern}*");
+
+        // the rarest trigram in literal fragments narrows candidates
+        let fragments = pattern.split(['?', '*']);
+        let trigram_postings: Option<Vec<_>> = fragments
+            .flat_map(|f| f.as_bytes().windows(3))
+            .map(|t| self.trigrams.get(&[t[0], t[1], t[2]]))
+            .collect();
+        let Some(trigram_postings) = trigram_postings else {
+            return vec![];
+        };
+        let matched = |call: &&String| matches_wildcard(call.as_bytes(), wrapped.as_bytes());
+        match trigram_postings.into_iter().min_by_key(|p| p.len()) {
+            Some(postings) => postings
+                .iter()
+                .map(|&i| &self.calls[i as usize])
+                .filter(matched)
+                .map(String::as_str)
+                .collect(),
+            None => self
+                .calls
+                .iter()
+                .filter(matched)
+                .map(String::as_str)
+                .collect(),
+        }
+    }
+
+    /// Finds other calls within the edit distance, nearest first.
+    pub fn near(&self, call: &str, max_distance: usize) -> Vec<(&str, usize)> {
+        let call = call.trim().to_ascii_uppercase();
+        let mut found: Vec<_> = self
+            .calls
+            .iter()
+            .filter(|c| c.len().abs_diff(call.len()) <= max_distance)
+            .map(|c| (c.as_str(), edit_distance(c, &call)))
+            .filter(|(_, d)| (1..=max_distance).contains(d))
+            .collect();
+        found.sort_by_key(|(c, d)| (*d, *c));
+        found
+    }
+}
+
+/// Matches the whole text against a pattern with `?` and `*`.
+fn matches_wildcard(text: &[u8], pattern: &[u8]) -> bool {
+    let (mut t, mut p) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    backtrack = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScpDatabase;
+
+    const MASTER_SCP: &str = "\
+# MASTER.SCP sample
+JA1ABC
+JL1HIS
+JL1HAS
+JH1HIS
+7K1HIS
+W1AW
+ja1abd
+";
+
+    #[test]
+    fn parses_list() {
+        let db = ScpDatabase::parse(MASTER_SCP);
+        assert_eq!(db.len(), 7);
+        assert!(db.contains("JA1ABD"));
+        assert!(!db.contains("MASTER.SCP"));
+    }
+
+    #[test]
+    fn searches_partial() {
+        let db = ScpDatabase::parse(MASTER_SCP);
+        assert_eq!(db.partial("JL1H?S"), vec!["JL1HAS", "JL1HIS"]);
+        assert_eq!(db.partial("*HIS"), vec!["7K1HIS", "JH1HIS", "JL1HIS"]);
+        assert_eq!(db.partial("1A"), vec!["JA1ABC", "JA1ABD", "W1AW"]);
+        assert_eq!(
+            db.partial("1H*S"),
+            vec!["7K1HIS", "JH1HIS", "JL1HAS", "JL1HIS"]
+        );
+        assert!(db.partial("ZZZ").is_empty());
+    }
+
+    #[test]
+    fn searches_near_miss() {
+        let db = ScpDatabase::parse(MASTER_SCP);
+        assert_eq!(db.near("JA1ABX", 1), vec![("JA1ABC", 1), ("JA1ABD", 1)]);
+        assert_eq!(db.near("JL1HIS", 1), vec![("JH1HIS", 1), ("JL1HAS", 1)]);
+    }
+}
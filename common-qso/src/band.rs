@@ -1,17 +1,24 @@
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
     str::FromStr,
 };
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
 use thiserror::Error as ThisError;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
 pub enum Band {
     Meter2190,
     Meter630,
     Meter560,
     Meter160,
     Meter80,
+    Meter60,
     Meter40,
+    Meter30,
     Meter20,
     Meter17,
     Meter15,
@@ -43,17 +50,109 @@ pub enum Band {
 #[error("invalid band specifier")]
 pub struct InvalidBand;
 
+impl Band {
+    const ALL: [Band; 33] = [
+        Band::Meter2190,
+        Band::Meter630,
+        Band::Meter560,
+        Band::Meter160,
+        Band::Meter80,
+        Band::Meter60,
+        Band::Meter40,
+        Band::Meter30,
+        Band::Meter20,
+        Band::Meter17,
+        Band::Meter15,
+        Band::Meter12,
+        Band::Meter10,
+        Band::Meter8,
+        Band::Meter6,
+        Band::Meter5,
+        Band::Meter4,
+        Band::Meter2,
+        Band::Meter1P25,
+        Band::Centimeter70,
+        Band::Centimeter33,
+        Band::Centimeter23,
+        Band::Centimeter13,
+        Band::Centimeter9,
+        Band::Centimeter6,
+        Band::Centimeter3,
+        Band::Centimeter1P25,
+        Band::Millimeter6,
+        Band::Millimeter4,
+        Band::Millimeter2P5,
+        Band::Millimeter2,
+        Band::Millimeter1,
+        Band::SubMillimeter,
+    ];
+
+    /// Frequency range in Hz, as defined in ADIF Band enumeration.
+    pub fn frequency_range(&self) -> RangeInclusive<u64> {
+        match self {
+            Band::Meter2190 => 135_700..=137_800,
+            Band::Meter630 => 472_000..=479_000,
+            Band::Meter560 => 501_000..=504_000,
+            Band::Meter160 => 1_800_000..=2_000_000,
+            Band::Meter80 => 3_500_000..=4_000_000,
+            Band::Meter60 => 5_060_000..=5_450_000,
+            Band::Meter40 => 7_000_000..=7_300_000,
+            Band::Meter30 => 10_100_000..=10_150_000,
+            Band::Meter20 => 14_000_000..=14_350_000,
+            Band::Meter17 => 18_068_000..=18_168_000,
+            Band::Meter15 => 21_000_000..=21_450_000,
+            Band::Meter12 => 24_890_000..=24_990_000,
+            Band::Meter10 => 28_000_000..=29_700_000,
+            Band::Meter8 => 40_000_000..=45_000_000,
+            Band::Meter6 => 50_000_000..=54_000_000,
+            Band::Meter5 => 54_000_001..=69_900_000,
+            Band::Meter4 => 70_000_000..=71_000_000,
+            Band::Meter2 => 144_000_000..=148_000_000,
+            Band::Meter1P25 => 222_000_000..=225_000_000,
+            Band::Centimeter70 => 420_000_000..=450_000_000,
+            Band::Centimeter33 => 902_000_000..=928_000_000,
+            Band::Centimeter23 => 1_240_000_000..=1_300_000_000,
+            Band::Centimeter13 => 2_300_000_000..=2_450_000_000,
+            Band::Centimeter9 => 3_300_000_000..=3_500_000_000,
+            Band::Centimeter6 => 5_650_000_000..=5_925_000_000,
+            Band::Centimeter3 => 10_000_000_000..=10_500_000_000,
+            Band::Centimeter1P25 => 24_000_000_000..=24_250_000_000,
+            Band::Millimeter6 => 47_000_000_000..=47_200_000_000,
+            Band::Millimeter4 => 75_500_000_000..=81_000_000_000,
+            Band::Millimeter2P5 => 119_980_000_000..=123_000_000_000,
+            Band::Millimeter2 => 134_000_000_000..=149_000_000_000,
+            Band::Millimeter1 => 241_000_000_000..=250_000_000_000,
+            Band::SubMillimeter => 300_000_000_000..=7_500_000_000_000,
+        }
+    }
+
+    /// Finds the band which contains the frequency in Hz.
+    pub fn from_frequency(hz: u64) -> Option<Band> {
+        Band::ALL
+            .into_iter()
+            .find(|b| b.frequency_range().contains(&hz))
+    }
+}
+
+/// Parses ADIF frequency in MHz (such as `7.0125`) into Hz.
+pub fn parse_frequency(mhz: &str) -> Option<u64> {
+    let mhz: f64 = mhz.trim().parse().ok()?;
+    (mhz.is_finite() && mhz >= 0.0).then(|| (mhz * 1_000_000.0).round() as u64)
+}
+
 impl FromStr for Band {
     type Err = InvalidBand;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+        match s.to_ascii_lowercase().as_str() {
             "2190m" => Ok(Band::Meter2190),
             "630m" => Ok(Band::Meter630),
             "560m" => Ok(Band::Meter560),
             "160m" => Ok(Band::Meter160),
             "80m" => Ok(Band::Meter80),
+            "60m" => Ok(Band::Meter60),
             "40m" => Ok(Band::Meter40),
+            "30m" => Ok(Band::Meter30),
             "20m" => Ok(Band::Meter20),
             "17m" => Ok(Band::Meter17),
             "15m" => Ok(Band::Meter15),
@@ -92,7 +191,9 @@ impl Display for Band {
             Band::Meter560 => write!(f, "560m"),
             Band::Meter160 => write!(f, "160m"),
             Band::Meter80 => write!(f, "80m"),
+            Band::Meter60 => write!(f, "60m"),
             Band::Meter40 => write!(f, "40m"),
+            Band::Meter30 => write!(f, "30m"),
             Band::Meter20 => write!(f, "20m"),
             Band::Meter17 => write!(f, "17m"),
             Band::Meter15 => write!(f, "15m"),
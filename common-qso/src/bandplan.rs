@@ -0,0 +1,371 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use compact_str::{CompactString, ToCompactString};
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+use crate::{
+    band::{Band, parse_frequency},
+    mode::ModeCategory,
+    record::QsoRecord,
+};
+
+const CW: &[ModeCategory] = &[ModeCategory::Cw];
+const NARROW: &[ModeCategory] = &[
+    ModeCategory::Cw,
+    ModeCategory::Data,
+    ModeCategory::WeakSignal,
+];
+const ALL: &[ModeCategory] = &[
+    ModeCategory::Phone,
+    ModeCategory::Cw,
+    ModeCategory::Data,
+    ModeCategory::WeakSignal,
+    ModeCategory::Image,
+    ModeCategory::Other,
+];
+
+/// Simplified JARL band plan (2023 revision).
+const JAPAN: &[(RangeInclusive<u64>, &[ModeCategory])] = &[
+    (135_700..=137_800, NARROW),
+    (472_000..=479_000, NARROW),
+    (1_800_000..=1_810_000, NARROW),
+    (1_810_000..=1_825_000, CW),
+    (1_825_000..=1_875_000, ALL),
+    (1_907_500..=1_912_500, NARROW),
+    (3_500_000..=3_520_000, CW),
+    (3_520_000..=3_535_000, NARROW),
+    (3_535_000..=3_575_000, ALL),
+    (3_599_000..=3_612_000, ALL),
+    (3_680_000..=3_687_000, ALL),
+    (3_702_000..=3_716_000, ALL),
+    (3_745_000..=3_770_000, ALL),
+    (3_791_000..=3_805_000, ALL),
+    (7_000_000..=7_030_000, CW),
+    (7_030_000..=7_045_000, NARROW),
+    (7_045_000..=7_200_000, ALL),
+    (10_100_000..=10_130_000, CW),
+    (10_130_000..=10_150_000, NARROW),
+    (14_000_000..=14_070_000, CW),
+    (14_070_000..=14_100_000, NARROW),
+    (14_100_000..=14_350_000, ALL),
+    (18_068_000..=18_100_000, CW),
+    (18_100_000..=18_110_000, NARROW),
+    (18_110_000..=18_168_000, ALL),
+    (21_000_000..=21_070_000, CW),
+    (21_070_000..=21_150_000, NARROW),
+    (21_150_000..=21_450_000, ALL),
+    (24_890_000..=24_920_000, CW),
+    (24_920_000..=24_930_000, NARROW),
+    (24_930_000..=24_990_000, ALL),
+    (28_000_000..=28_070_000, CW),
+    (28_070_000..=28_150_000, NARROW),
+    (28_150_000..=29_700_000, ALL),
+    (50_000_000..=50_100_000, CW),
+    (50_100_000..=54_000_000, ALL),
+    (144_000_000..=144_100_000, NARROW),
+    (144_100_000..=146_000_000, ALL),
+    (430_000_000..=430_100_000, NARROW),
+    (430_100_000..=440_000_000, ALL),
+    (1_260_000_000..=1_300_000_000, ALL),
+    (2_400_000_000..=2_450_000_000, ALL),
+    (5_650_000_000..=5_850_000_000, ALL),
+    (10_000_000_000..=10_250_000_000, ALL),
+    (24_000_000_000..=24_050_000_000, ALL),
+];
+
+/// Japanese amateur radio operator licence class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LicenseClass {
+    First,
+    Second,
+    Third,
+    Fourth,
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("invalid license class")]
+pub struct InvalidLicenseClass;
+
+impl LicenseClass {
+    /// Maximum transmitter power in watts, or `None` if the band is not permitted.
+    pub fn power_limit(&self, band: Band) -> Option<f64> {
+        let below_30mhz = band < Band::Meter8;
+        match (self, band) {
+            (LicenseClass::First, _) => Some(1000.0),
+            (LicenseClass::Second, _) => Some(200.0),
+            (LicenseClass::Third, Band::Meter30 | Band::Meter20) => None,
+            (LicenseClass::Third, _) => Some(50.0),
+            (LicenseClass::Fourth, Band::Meter30 | Band::Meter20 | Band::Meter17) => None,
+            (LicenseClass::Fourth, _) if below_30mhz => Some(10.0),
+            (LicenseClass::Fourth, _) => Some(20.0),
+        }
+    }
+
+    /// Whether the class can operate the mode; 4th class cannot operate Morse code.
+    pub fn permits_mode(&self, category: ModeCategory) -> bool {
+        !matches!((self, category), (LicenseClass::Fourth, ModeCategory::Cw))
+    }
+}
+
+impl FromStr for LicenseClass {
+    type Err = InvalidLicenseClass;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "first" => Ok(LicenseClass::First),
+            "2" | "second" => Ok(LicenseClass::Second),
+            "3" | "third" => Ok(LicenseClass::Third),
+            "4" | "fourth" => Ok(LicenseClass::Fourth),
+            _ => Err(InvalidLicenseClass),
+        }
+    }
+}
+
+impl Display for LicenseClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LicenseClass::First => write!(f, "first"),
+            LicenseClass::Second => write!(f, "second"),
+            LicenseClass::Third => write!(f, "third"),
+            LicenseClass::Fourth => write!(f, "fourth"),
+        }
+    }
+}
+
+/// Band plan segment in Hz, and mode categories permitted in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub range: RangeInclusive<u64>,
+    pub categories: Vec<ModeCategory>,
+}
+
+/// Problem which would make a QSO look non-compliant.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum ComplianceIssue {
+    /// FREQ is not a number.
+    InvalidFrequency { frequency: CompactString },
+
+    /// FREQ is not within BAND.
+    BandMismatch { band: Band, frequency_hz: u64 },
+
+    /// FREQ is not within any segment.
+    OutsideBandPlan { frequency_hz: u64 },
+
+    /// Segment does not permit the mode.
+    ModeNotPermitted {
+        frequency_hz: u64,
+        category: ModeCategory,
+    },
+
+    /// Licence class cannot operate on the band.
+    BandNotPermitted { band: Band },
+
+    /// Licence class cannot operate the mode.
+    ModeNotLicensed { category: ModeCategory },
+
+    /// TX_PWR exceeds the licence limit.
+    OverPower { power: f64, limit: f64 },
+}
+
+impl Display for ComplianceIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ComplianceIssue::InvalidFrequency { frequency } => {
+                write!(f, "invalid frequency {frequency}")
+            }
+            ComplianceIssue::BandMismatch { band, frequency_hz } => {
+                write!(f, "{frequency_hz} Hz is not in {band} band")
+            }
+            ComplianceIssue::OutsideBandPlan { frequency_hz } => {
+                write!(f, "{frequency_hz} Hz is outside of band plan")
+            }
+            ComplianceIssue::ModeNotPermitted {
+                frequency_hz,
+                category,
+            } => write!(f, "{category} mode is not permitted at {frequency_hz} Hz"),
+            ComplianceIssue::BandNotPermitted { band } => {
+                write!(f, "{band} band is not permitted for the license")
+            }
+            ComplianceIssue::ModeNotLicensed { category } => {
+                write!(f, "{category} mode is not permitted for the license")
+            }
+            ComplianceIssue::OverPower { power, limit } => {
+                write!(f, "{power} W exceeds the limit of {limit} W")
+            }
+        }
+    }
+}
+
+/// Segments of a band plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BandPlan {
+    segments: Vec<Segment>,
+}
+
+impl BandPlan {
+    pub fn new(segments: Vec<Segment>) -> BandPlan {
+        BandPlan { segments }
+    }
+
+    /// Band plan in Japan.
+    pub fn japan() -> BandPlan {
+        let segments = JAPAN
+            .iter()
+            .map(|(range, categories)| Segment {
+                range: range.clone(),
+                categories: categories.to_vec(),
+            })
+            .collect();
+        BandPlan { segments }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Checks frequency, mode and power of a QSO.
+    pub fn check(&self, record: &QsoRecord, license: LicenseClass) -> Vec<ComplianceIssue> {
+        let mut issues = vec![];
+        let power_limit = license.power_limit(record.band);
+        match (power_limit, record.tx_power.as_deref()) {
+            (None, _) => issues.push(ComplianceIssue::BandNotPermitted { band: record.band }),
+            (Some(limit), Some(power)) => match power.parse::<f64>() {
+                Ok(power) if power > limit => {
+                    issues.push(ComplianceIssue::OverPower { power, limit })
+                }
+                _ => (),
+            },
+            (Some(_), None) => (),
+        }
+        let category = record.mode_category();
+        if !license.permits_mode(category) {
+            issues.push(ComplianceIssue::ModeNotLicensed { category });
+        }
+
+        let Some(frequency_hz) = parse_frequency(&record.frequency) else {
+            issues.push(ComplianceIssue::InvalidFrequency {
+                frequency: record.frequency.to_compact_string(),
+            });
+            return issues;
+        };
+        if !record.band.frequency_range().contains(&frequency_hz) {
+            issues.push(ComplianceIssue::BandMismatch {
+                band: record.band,
+                frequency_hz,
+            });
+        }
+
+        let mut segments = self
+            .segments
+            .iter()
+            .filter(|s| s.range.contains(&frequency_hz))
+            .peekable();
+        if segments.peek().is_none() {
+            issues.push(ComplianceIssue::OutsideBandPlan { frequency_hz });
+        } else if !segments.any(|s| s.categories.contains(&category)) {
+            issues.push(ComplianceIssue::ModeNotPermitted {
+                frequency_hz,
+                category,
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+    use time::UtcOffset;
+
+    use super::{BandPlan, ComplianceIssue, LicenseClass};
+    use crate::{band::Band, mode::ModeCategory, record::QsoRecord};
+
+    fn record(band: &str, mode: &str, freq: &str, power: &str) -> QsoRecord {
+        let adi = format!(
+            "<QSO_DATE:8>20250102<TIME_ON:6>120000<CALL:6>JA1ZZZ<BAND:{}>{band}<MODE:{}>{mode}<FREQ:{}>{freq}<TX_PWR:{}>{power}<eor>",
+            band.len(),
+            mode.len(),
+            freq.len(),
+            power.len()
+        );
+        let adif = read_adi(&adi, LengthMode::Bytes).expect("valid ADI");
+        QsoRecord::new(&adif.records()[0], UtcOffset::UTC).expect("valid QSO")
+    }
+
+    #[test]
+    fn accepts_compliant_qsos() {
+        let plan = BandPlan::japan();
+        assert!(
+            plan.check(&record("40m", "CW", "7.010", "50"), LicenseClass::Third)
+                .is_empty()
+        );
+        assert!(
+            plan.check(&record("20m", "FT8", "14.074", "100"), LicenseClass::Second)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn flags_segments() {
+        let plan = BandPlan::japan();
+        assert_eq!(
+            plan.check(&record("40m", "SSB", "7.010", "10"), LicenseClass::First),
+            vec![ComplianceIssue::ModeNotPermitted {
+                frequency_hz: 7_010_000,
+                category: ModeCategory::Phone
+            }]
+        );
+        assert_eq!(
+            plan.check(&record("40m", "SSB", "7.250", "10"), LicenseClass::First),
+            vec![ComplianceIssue::OutsideBandPlan {
+                frequency_hz: 7_250_000
+            }]
+        );
+        assert_eq!(
+            plan.check(&record("20m", "CW", "7.010", "10"), LicenseClass::First),
+            vec![ComplianceIssue::BandMismatch {
+                band: Band::Meter20,
+                frequency_hz: 7_010_000
+            }]
+        );
+    }
+
+    #[test]
+    fn flags_license_limits() {
+        let plan = BandPlan::japan();
+        assert_eq!(
+            plan.check(&record("40m", "SSB", "7.100", "20"), LicenseClass::Fourth),
+            vec![ComplianceIssue::OverPower {
+                power: 20.0,
+                limit: 10.0
+            }]
+        );
+        assert_eq!(
+            plan.check(&record("40m", "CW", "7.010", "10"), LicenseClass::Fourth),
+            vec![ComplianceIssue::ModeNotLicensed {
+                category: ModeCategory::Cw
+            }]
+        );
+        assert!(
+            plan.check(&record("40m", "CW", "7.010", "10"), LicenseClass::Third)
+                .is_empty()
+        );
+        assert_eq!(
+            plan.check(&record("2m", "FM", "145.000", "20"), LicenseClass::Fourth),
+            vec![]
+        );
+        assert_eq!(
+            plan.check(&record("20m", "CW", "14.010", "10"), LicenseClass::Third),
+            vec![ComplianceIssue::BandNotPermitted {
+                band: Band::Meter20
+            }]
+        );
+    }
+}
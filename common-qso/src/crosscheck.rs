@@ -0,0 +1,580 @@
+use std::collections::{HashMap, HashSet};
+
+use callfind::callsign::{base_callsign, edit_distance};
+use compact_str::CompactString;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+use time::Duration;
+
+use crate::{band::Band, exchange::QsoExchange, qso::Qso, record::QsoRecord, report::Report};
+
+/// Log submitted by a station.
+#[derive(Debug, Clone)]
+pub struct StationLog {
+    /// Callsign of the station which submitted this log.
+    pub station: CompactString,
+    pub qsos: Vec<Qso>,
+}
+
+/// Tolerances applied when pairing QSOs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossCheckTolerance {
+    /// Time difference regarded as clock skew.
+    pub clock_skew: Duration,
+
+    /// Time difference beyond `clock_skew` reported as `TimeMismatch` instead of NIL.
+    pub time_mismatch_limit: Duration,
+
+    /// Frequency difference in kHz accepted even if logged bands differ.
+    pub band_slop_khz: f64,
+
+    /// Whether signal reports are compared as part of exchange.
+    pub compare_report: bool,
+}
+
+impl Default for CrossCheckTolerance {
+    fn default() -> CrossCheckTolerance {
+        CrossCheckTolerance {
+            clock_skew: Duration::minutes(5),
+            time_mismatch_limit: Duration::minutes(30),
+            band_slop_khz: 10.0,
+            compare_report: false,
+        }
+    }
+}
+
+/// Position of a QSO as indices of log and QSO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct QsoLocation {
+    pub log: usize,
+    pub qso: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CheckStatus {
+    Confirmed {
+        counterpart: QsoLocation,
+    },
+
+    /// Counterpart log exists but has no corresponding QSO.
+    NotInLog,
+
+    /// Logged callsign is wrong; `correct` is the station which logged this QSO.
+    BustedCall {
+        correct: CompactString,
+        counterpart: QsoLocation,
+    },
+
+    /// Received exchange differs from what the counterpart sent.
+    BustedExchange {
+        expected: QsoExchange,
+        counterpart: QsoLocation,
+    },
+
+    WrongBand {
+        counterpart_band: Band,
+        counterpart: QsoLocation,
+    },
+
+    /// Counterpart found outside of `clock_skew`.
+    TimeMismatch {
+        difference_seconds: i64,
+        counterpart: QsoLocation,
+    },
+
+    /// Contacted station did not submit a log.
+    NoCounterpartLog,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum CrossCheckError {
+    #[error("log {0} has no station callsign")]
+    MissingStation(usize),
+
+    #[error("logs {first} and {second} are both of {station}")]
+    DuplicateStation {
+        station: String,
+        first: usize,
+        second: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckResult {
+    #[serde(flatten)]
+    pub location: QsoLocation,
+
+    #[serde(flatten)]
+    pub status: CheckStatus,
+}
+
+/// Cross-checks QSOs between logs of contest participants.
+#[derive(Debug)]
+pub struct CrossCheck<'a> {
+    logs: &'a [StationLog],
+    tolerance: CrossCheckTolerance,
+
+    /// Base callsign of log owner to log index.
+    owners: HashMap<String, usize>,
+
+    /// Base callsign of worked station to QSO locations.
+    worked: HashMap<String, Vec<QsoLocation>>,
+
+    /// QSO to its counterpart with rank and time difference, in both directions.
+    /// Each QSO is paired with at most one QSO.
+    pairs: HashMap<QsoLocation, (u8, Duration, QsoLocation)>,
+
+    /// Unpaired QSO with busted callsign to the QSO logged by the correct station.
+    /// Each counterpart is claimed by at most one QSO.
+    busted: HashMap<QsoLocation, QsoLocation>,
+}
+
+impl<'a> CrossCheck<'a> {
+    /// Fails if a log has no station callsign, or logs of the same station are given.
+    pub fn new(
+        logs: &'a [StationLog],
+        tolerance: CrossCheckTolerance,
+    ) -> Result<CrossCheck<'a>, CrossCheckError> {
+        let mut owners = HashMap::new();
+        let mut worked: HashMap<_, Vec<_>> = HashMap::new();
+        for (log_index, log) in logs.iter().enumerate() {
+            let owner = base_callsign(&log.station);
+            if owner.is_empty() {
+                return Err(CrossCheckError::MissingStation(log_index));
+            }
+            if let Some(&first) = owners.get(&owner) {
+                return Err(CrossCheckError::DuplicateStation {
+                    station: owner,
+                    first,
+                    second: log_index,
+                });
+            }
+            owners.insert(owner, log_index);
+            for (qso_index, qso) in log.qsos.iter().enumerate() {
+                worked
+                    .entry(base_callsign(&qso.record.call))
+                    .or_default()
+                    .push(QsoLocation {
+                        log: log_index,
+                        qso: qso_index,
+                    });
+            }
+        }
+
+        let mut cross_check = CrossCheck {
+            logs,
+            tolerance,
+            owners,
+            worked,
+            pairs: HashMap::new(),
+            busted: HashMap::new(),
+        };
+        cross_check.pair_qsos();
+        cross_check.pair_busted_calls();
+        Ok(cross_check)
+    }
+
+    /// Pairs QSOs between each two logs greedily, best rank and smallest time difference first.
+    fn pair_qsos(&mut self) {
+        let mut candidates = vec![];
+        for (log_index, log) in self.logs.iter().enumerate() {
+            let owner_base = base_callsign(&log.station);
+            let Some(worked_owner) = self.worked.get(&owner_base) else {
+                continue;
+            };
+            for (qso_index, qso) in log.qsos.iter().enumerate() {
+                // each two logs are paired once from the former
+                let Some(&counterpart_log) = self.owners.get(&base_callsign(&qso.record.call))
+                else {
+                    continue;
+                };
+                if counterpart_log <= log_index {
+                    continue;
+                }
+                let location = QsoLocation {
+                    log: log_index,
+                    qso: qso_index,
+                };
+                for &c in worked_owner.iter().filter(|c| c.log == counterpart_log) {
+                    let other = &self.logs[c.log].qsos[c.qso];
+                    if let Some((rank, difference)) = self.rank(qso, other) {
+                        candidates.push((rank, difference, location, c));
+                    }
+                }
+            }
+        }
+
+        candidates.sort_by_key(|&(rank, difference, l, c)| {
+            (rank, difference, l.log, l.qso, c.log, c.qso)
+        });
+        let mut paired = HashSet::new();
+        for (rank, difference, location, counterpart) in candidates {
+            if paired.contains(&location) || paired.contains(&counterpart) {
+                continue;
+            }
+            paired.insert(location);
+            paired.insert(counterpart);
+            self.pairs.insert(location, (rank, difference, counterpart));
+            self.pairs.insert(counterpart, (rank, difference, location));
+        }
+    }
+
+    /// Pairs unpaired QSOs with QSOs made by a station with similar callsign,
+    /// greedily with the smallest time difference first.
+    fn pair_busted_calls(&mut self) {
+        let mut candidates = vec![];
+        for (log_index, log) in self.logs.iter().enumerate() {
+            let Some(worked_owner) = self.worked.get(&base_callsign(&log.station)) else {
+                continue;
+            };
+            for (qso_index, qso) in log.qsos.iter().enumerate() {
+                let location = QsoLocation {
+                    log: log_index,
+                    qso: qso_index,
+                };
+                if self.pairs.contains_key(&location) {
+                    continue;
+                }
+                let worked_base = base_callsign(&qso.record.call);
+                for &c in worked_owner {
+                    if c.log == log_index || self.pairs.contains_key(&c) {
+                        continue;
+                    }
+                    let station_base = base_callsign(&self.logs[c.log].station);
+                    if station_base == worked_base || edit_distance(&station_base, &worked_base) > 2
+                    {
+                        continue;
+                    }
+                    let other = &self.logs[c.log].qsos[c.qso];
+                    let difference = (qso.record.datetime - other.record.datetime).abs();
+                    if difference <= self.tolerance.clock_skew
+                        && self.band_matches(&qso.record, &other.record)
+                    {
+                        candidates.push((difference, location, c));
+                    }
+                }
+            }
+        }
+
+        candidates.sort_by_key(|&(difference, l, c)| (difference, l.log, l.qso, c.log, c.qso));
+        let mut claimed = HashSet::new();
+        for (_, location, counterpart) in candidates {
+            if self.busted.contains_key(&location) || claimed.contains(&counterpart) {
+                continue;
+            }
+            claimed.insert(counterpart);
+            self.busted.insert(location, counterpart);
+        }
+    }
+
+    /// Ranks a candidate counterpart; 0: matched, 1: wrong band, 2: time mismatch.
+    fn rank(&self, qso: &Qso, other: &Qso) -> Option<(u8, Duration)> {
+        let difference = (qso.record.datetime - other.record.datetime).abs();
+        let band_matches = self.band_matches(&qso.record, &other.record);
+        let rank = match (band_matches, difference) {
+            (true, d) if d <= self.tolerance.clock_skew => 0,
+            (false, d) if d <= self.tolerance.clock_skew => 1,
+            (true, d) if d <= self.tolerance.time_mismatch_limit => 2,
+            _ => return None,
+        };
+        Some((rank, difference))
+    }
+
+    /// Checks all QSOs in all logs.
+    pub fn check_all(&self) -> Vec<CheckResult> {
+        self.logs
+            .iter()
+            .enumerate()
+            .flat_map(|(log, l)| (0..l.qsos.len()).map(move |qso| QsoLocation { log, qso }))
+            .map(|location| CheckResult {
+                location,
+                status: self.check(location),
+            })
+            .collect()
+    }
+
+    /// Checks a QSO against the log of contacted station.
+    pub fn check(&self, location: QsoLocation) -> CheckStatus {
+        let log = &self.logs[location.log];
+        let qso = &log.qsos[location.qso];
+        let worked_base = base_callsign(&qso.record.call);
+
+        let counterpart_log = self
+            .owners
+            .get(&worked_base)
+            .copied()
+            .filter(|&l| l != location.log);
+        if counterpart_log.is_some()
+            && let Some(status) = self.check_counterpart(qso, location)
+        {
+            return status;
+        }
+
+        if let Some(&counterpart) = self.busted.get(&location) {
+            return CheckStatus::BustedCall {
+                correct: self.logs[counterpart.log].station.clone(),
+                counterpart,
+            };
+        }
+        match counterpart_log {
+            Some(_) => CheckStatus::NotInLog,
+            None => CheckStatus::NoCounterpartLog,
+        }
+    }
+
+    fn check_counterpart(&self, qso: &Qso, location: QsoLocation) -> Option<CheckStatus> {
+        let &(rank, difference, counterpart) = self.pairs.get(&location)?;
+        let other = &self.logs[counterpart.log].qsos[counterpart.qso];
+        let status = match rank {
+            0 if self.exchange_matches(&qso.exchanges.received, &other.exchanges.sent) => {
+                CheckStatus::Confirmed { counterpart }
+            }
+            0 => CheckStatus::BustedExchange {
+                expected: other.exchanges.sent.clone(),
+                counterpart,
+            },
+            1 => CheckStatus::WrongBand {
+                counterpart_band: other.record.band,
+                counterpart,
+            },
+            _ => CheckStatus::TimeMismatch {
+                difference_seconds: difference.whole_seconds(),
+                counterpart,
+            },
+        };
+        Some(status)
+    }
+
+    fn band_matches(&self, left: &QsoRecord, right: &QsoRecord) -> bool {
+        if left.band == right.band {
+            return true;
+        }
+        match (
+            left.frequency.parse::<f64>(),
+            right.frequency.parse::<f64>(),
+        ) {
+            (Ok(l), Ok(r)) => (l - r).abs() * 1000.0 <= self.tolerance.band_slop_khz,
+            _ => false,
+        }
+    }
+
+    fn exchange_matches(&self, received: &QsoExchange, sent: &QsoExchange) -> bool {
+        if normalize_number(received.number.as_deref()) != normalize_number(sent.number.as_deref())
+        {
+            return false;
+        }
+        if !self.tolerance.compare_report {
+            return true;
+        }
+        parse_report(received.report.as_deref()) == parse_report(sent.report.as_deref())
+    }
+}
+
+/// Uppercases and strips leading zeros of serial numbers (`001` equals `1`).
+fn normalize_number(number: Option<&str>) -> Option<String> {
+    let number = number?.trim().to_ascii_uppercase();
+    if !number.is_empty() && number.bytes().all(|c| c.is_ascii_digit()) {
+        let stripped = number.trim_start_matches('0');
+        Some(if stripped.is_empty() { "0" } else { stripped }.to_string())
+    } else {
+        Some(number)
+    }
+}
+
+/// Parses report, falling back to trimmed text for malformed ones.
+fn parse_report(report: Option<&str>) -> Option<Result<Report, &str>> {
+    report.map(|r| r.parse().map_err(|_| r.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+    use time::UtcOffset;
+
+    use super::{CheckStatus, CrossCheck, CrossCheckError, QsoLocation, StationLog};
+    use crate::{
+        band::Band,
+        qso::{Qso, Strictness},
+    };
+
+    fn log(station: &str, adi: &str) -> StationLog {
+        let adif = read_adi(adi, LengthMode::Bytes).expect("valid ADI");
+        let qsos = adif
+            .records()
+            .iter()
+            .map(|r| Qso::new(r, UtcOffset::UTC, Strictness::Lenient).expect("valid QSO"))
+            .collect();
+        StationLog {
+            station: station.into(),
+            qsos,
+        }
+    }
+
+    fn logs() -> Vec<StationLog> {
+        vec![
+            log(
+                "JA1AAA",
+                concat!(
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120000<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>1<SRX:1>5<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>121000<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>2<SRX:1>7<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>122000<BAND:3>20m<MODE:2>CW<CALL:6>JA1BBB<FREQ:6>14.010<STX:1>3<SRX:1>8<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>125000<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>4<SRX:1>9<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>124000<BAND:3>40m<MODE:2>CW<CALL:6>JA1CCX<FREQ:5>7.010<STX:1>5<SRX:1>1<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>140000<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>6<SRX:2>10<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>141000<BAND:3>40m<MODE:2>CW<CALL:6>JA1DDD<FREQ:5>7.010<STX:1>7<SRX:1>1<eor>",
+                ),
+            ),
+            log(
+                "JA1BBB",
+                concat!(
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120100<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:3>005<SRX:1>1<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>121000<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>6<SRX:1>2<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>122000<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>8<SRX:1>3<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>130500<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>9<SRX:1>4<eor>",
+                ),
+            ),
+            log(
+                "JA1CCC",
+                "<QSO_DATE:8>20250102<TIME_ON:6>124000<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>1<SRX:1>5<eor>",
+            ),
+        ]
+    }
+
+    #[test]
+    fn classifies_qsos() {
+        let logs = logs();
+        let checker = CrossCheck::new(&logs, Default::default()).expect("distinct stations");
+        let status = |qso| checker.check(QsoLocation { log: 0, qso });
+        let counterpart = |qso| QsoLocation { log: 1, qso };
+
+        assert_eq!(
+            status(0),
+            CheckStatus::Confirmed {
+                counterpart: counterpart(0)
+            }
+        );
+        assert!(matches!(
+            status(1),
+            CheckStatus::BustedExchange { expected, .. } if expected.number.as_deref() == Some("6")
+        ));
+        assert_eq!(
+            status(2),
+            CheckStatus::WrongBand {
+                counterpart_band: Band::Meter40,
+                counterpart: counterpart(2)
+            }
+        );
+        assert_eq!(
+            status(3),
+            CheckStatus::TimeMismatch {
+                difference_seconds: 900,
+                counterpart: counterpart(3)
+            }
+        );
+        assert_eq!(
+            status(4),
+            CheckStatus::BustedCall {
+                correct: "JA1CCC".into(),
+                counterpart: QsoLocation { log: 2, qso: 0 }
+            }
+        );
+        assert_eq!(status(5), CheckStatus::NotInLog);
+        assert_eq!(status(6), CheckStatus::NoCounterpartLog);
+    }
+
+    #[test]
+    fn pairs_counterpart_once() {
+        let logs = vec![
+            log(
+                "JA1AAA",
+                concat!(
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120000<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>1<SRX:1>1<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120100<BAND:3>40m<MODE:2>CW<CALL:6>JA1BBB<FREQ:5>7.010<STX:1>1<SRX:1>1<eor>",
+                ),
+            ),
+            log(
+                "JA1BBB",
+                "<QSO_DATE:8>20250102<TIME_ON:6>120100<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>1<SRX:1>1<eor>",
+            ),
+        ];
+        let checker = CrossCheck::new(&logs, Default::default()).expect("distinct stations");
+        let status = |log, qso| checker.check(QsoLocation { log, qso });
+
+        assert_eq!(status(0, 0), CheckStatus::NotInLog);
+        assert_eq!(
+            status(0, 1),
+            CheckStatus::Confirmed {
+                counterpart: QsoLocation { log: 1, qso: 0 }
+            }
+        );
+        assert_eq!(
+            status(1, 0),
+            CheckStatus::Confirmed {
+                counterpart: QsoLocation { log: 0, qso: 1 }
+            }
+        );
+    }
+
+    #[test]
+    fn claims_busted_counterpart_once() {
+        let logs = vec![
+            log(
+                "JA1AAA",
+                concat!(
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120200<BAND:3>40m<MODE:2>CW<CALL:6>JA1CCX<FREQ:5>7.010<STX:1>1<SRX:1>1<eor>",
+                    "<QSO_DATE:8>20250102<TIME_ON:6>120000<BAND:3>40m<MODE:2>CW<CALL:6>JA1CCY<FREQ:5>7.010<STX:1>2<SRX:1>1<eor>",
+                ),
+            ),
+            log(
+                "JA1CCC",
+                "<QSO_DATE:8>20250102<TIME_ON:6>120100<BAND:3>40m<MODE:2>CW<CALL:6>JA1AAA<FREQ:5>7.010<STX:1>1<SRX:1>1<eor>",
+            ),
+        ];
+        let checker = CrossCheck::new(&logs, Default::default()).expect("distinct stations");
+        let status = |qso| checker.check(QsoLocation { log: 0, qso });
+
+        assert_eq!(
+            status(0),
+            CheckStatus::BustedCall {
+                correct: "JA1CCC".into(),
+                counterpart: QsoLocation { log: 1, qso: 0 }
+            }
+        );
+        assert_eq!(status(1), CheckStatus::NoCounterpartLog);
+    }
+
+    #[test]
+    fn rejects_ambiguous_owners() {
+        let mut logs = logs();
+        logs[2].station = "JA1BBB/P".into();
+        assert_eq!(
+            CrossCheck::new(&logs, Default::default()).err(),
+            Some(CrossCheckError::DuplicateStation {
+                station: "JA1BBB".into(),
+                first: 1,
+                second: 2
+            })
+        );
+
+        logs[2].station = "".into();
+        assert_eq!(
+            CrossCheck::new(&logs, Default::default()).err(),
+            Some(CrossCheckError::MissingStation(2))
+        );
+    }
+
+    #[test]
+    fn serializes_report() {
+        let logs = logs();
+        let results = CrossCheck::new(&logs, Default::default())
+            .expect("distinct stations")
+            .check_all();
+        assert_eq!(results.len(), 12);
+
+        let value = serde_json::to_value(&results[0]).expect("serializable");
+        assert_eq!(value["log"], 0);
+        assert_eq!(value["status"], "confirmed");
+        assert_eq!(value["counterpart"]["qso"], 0);
+    }
+}
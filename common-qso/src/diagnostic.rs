@@ -0,0 +1,160 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use adif_reader::document::Record;
+use compact_str::{CompactString, ToCompactString};
+
+use crate::error::QsoError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Field is valid but incomplete, and never rejects a QSO.
+    Info,
+
+    /// Optional field is malformed and ignored.
+    Warning,
+
+    /// Required field is missing or malformed.
+    Error,
+}
+
+/// Describes a problem found in an ADIF field.
+#[derive(Debug, Clone)]
+pub struct FieldDiagnostic {
+    pub field: CompactString,
+    pub value: Option<CompactString>,
+    pub reason: QsoError,
+    pub severity: Severity,
+}
+
+impl Display for FieldDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.value {
+            Some(value) => write!(f, "{} = {value:?}: {}", self.field, self.reason),
+            None => write!(f, "{}: {}", self.field, self.reason),
+        }
+    }
+}
+
+/// Reads fields from `Record` while collecting diagnostics.
+pub(crate) struct FieldReader<'a> {
+    record: &'a Record,
+    diagnostics: Vec<FieldDiagnostic>,
+
+    /// Raw text of present fields which could not be modeled.
+    preserved: BTreeMap<CompactString, CompactString>,
+}
+
+impl<'a> FieldReader<'a> {
+    pub fn new(record: &'a Record) -> FieldReader<'a> {
+        FieldReader {
+            record,
+            diagnostics: vec![],
+            preserved: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the first present field of `fields`.
+    pub fn text(&self, fields: &[&str]) -> Option<&'a str> {
+        assert!(!fields.is_empty(), "fields must be specified");
+        fields.iter().find_map(|f| self.record.field(*f))
+    }
+
+    pub fn required_text(&mut self, field: &'static str) -> Option<&'a str> {
+        let value = self.record.field(field);
+        if value.is_none() {
+            self.diagnostics.push(FieldDiagnostic {
+                field: field.to_compact_string(),
+                value: None,
+                reason: QsoError::MissingAdifField(field),
+                severity: Severity::Error,
+            });
+        }
+        value
+    }
+
+    pub fn required<T>(
+        &mut self,
+        field: &'static str,
+        parse: impl FnOnce(&str) -> Result<T, QsoError>,
+    ) -> Option<T> {
+        let value = self.required_text(field)?;
+        self.parse(field, value, parse, Severity::Error)
+    }
+
+    pub fn optional<T>(
+        &mut self,
+        field: &str,
+        parse: impl FnOnce(&str) -> Result<T, QsoError>,
+    ) -> Option<T> {
+        let value = self.record.field(field)?;
+        self.parse(field, value, parse, Severity::Warning)
+    }
+
+    /// Records a warning on a present field which conflicts with others.
+    pub fn warn(&mut self, field: &str, reason: QsoError) {
+        self.report(field, reason, Severity::Warning);
+    }
+
+    /// Records a note on a valid field which lacks its counterpart.
+    pub fn note(&mut self, field: &str, reason: QsoError) {
+        self.report(field, reason, Severity::Info);
+    }
+
+    /// Keeps the raw text of a present field, which the parts cannot write back.
+    pub fn preserve(&mut self, field: &str) {
+        if let Some(value) = self.record.field(field) {
+            self.preserved
+                .insert(field.to_compact_string(), value.to_compact_string());
+        }
+    }
+
+    /// Splits into diagnostics and raw text of the preserved fields.
+    /// Malformed optional fields are preserved.
+    pub fn into_parts(self) -> (Vec<FieldDiagnostic>, BTreeMap<CompactString, CompactString>) {
+        (self.diagnostics, self.preserved)
+    }
+
+    /// Converts into `Result`, failing with the first diagnostic if exists.
+    pub fn finish<T>(self, value: Option<T>) -> Result<T, QsoError> {
+        match self.diagnostics.into_iter().next() {
+            Some(d) => Err(d.reason),
+            None => Ok(value.expect("value must exist without diagnostics")),
+        }
+    }
+
+    fn report(&mut self, field: &str, reason: QsoError, severity: Severity) {
+        self.diagnostics.push(FieldDiagnostic {
+            field: field.to_compact_string(),
+            value: self.record.field(field).map(|v| v.to_compact_string()),
+            reason,
+            severity,
+        });
+    }
+
+    fn parse<T>(
+        &mut self,
+        field: &str,
+        value: &str,
+        parse: impl FnOnce(&str) -> Result<T, QsoError>,
+        severity: Severity,
+    ) -> Option<T> {
+        match parse(value) {
+            Ok(v) => Some(v),
+            Err(reason) => {
+                if severity == Severity::Warning {
+                    self.preserve(field);
+                }
+                self.diagnostics.push(FieldDiagnostic {
+                    field: field.to_compact_string(),
+                    value: Some(value.to_compact_string()),
+                    reason,
+                    severity,
+                });
+                None
+            }
+        }
+    }
+}
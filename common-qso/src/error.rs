@@ -1,9 +1,15 @@
+use std::num::{ParseFloatError, ParseIntError};
+
+use callfind::{grid_locator::GridLocatorError, location::LocationError};
 use thiserror::Error as ThisError;
 use time::error::Parse as TimeParseError;
 
-use crate::band::InvalidBand;
+use crate::{
+    band::InvalidBand, propagation::InvalidPropagation, reference::InvalidReference,
+    report::ReportError, station::InvalidContinent,
+};
 
-#[derive(Debug, ThisError)]
+#[derive(Debug, Clone, ThisError)]
 pub enum QsoError {
     #[error("missing ADIF field: {0}")]
     MissingAdifField(&'static str),
@@ -16,4 +22,37 @@ pub enum QsoError {
 
     #[error("QSL status parse error")]
     QslParse,
+
+    #[error("integer parse error: {0}")]
+    IntegerParse(#[from] ParseIntError),
+
+    #[error("number parse error: {0}")]
+    FloatParse(#[from] ParseFloatError),
+
+    #[error("grid locator parse error: {0}")]
+    GridLocatorParse(#[from] GridLocatorError),
+
+    #[error("location parse error: {0}")]
+    LocationParse(#[from] LocationError),
+
+    #[error("grid locator does not contain location")]
+    LocationMismatch,
+
+    #[error("latitude and longitude must be paired")]
+    IncompleteLocation,
+
+    #[error("continent parse error")]
+    ContinentParse(#[from] InvalidContinent),
+
+    #[error("propagation mode parse error")]
+    PropagationParse(#[from] InvalidPropagation),
+
+    #[error("reference error: {0}")]
+    ReferenceParse(#[from] InvalidReference),
+
+    #[error("report error: {0}")]
+    ReportParse(#[from] ReportError),
+
+    #[error("ADIF field out of range: {0}")]
+    OutOfRange(&'static str),
 }
@@ -1,15 +1,78 @@
+mod template;
+
 use adif_reader::document::Record;
 use compact_str::{CompactString, ToCompactString};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    get_optional_field_oneof,
+    mode::ModeCategory,
+    report::{Report, ReportError},
+    set_optional_field,
+};
 
-use crate::get_optional_field_oneof;
+pub use callfind::japan::JarlAreaKind;
+pub use template::{
+    ExchangeComponent, ExchangeError, ExchangePart, ExchangeTemplate, JarlArea, ParsedExchange,
+    PowerCode,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QsoExchange {
     pub report: Option<CompactString>,
     pub number: Option<CompactString>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl QsoExchange {
+    /// Parses report and validates it against the mode category.
+    pub fn parsed_report(&self, category: ModeCategory) -> Option<Result<Report, ReportError>> {
+        self.report
+            .as_deref()
+            .map(|r| Report::parse_for(r, category))
+    }
+
+    /// Writes back into ADIF fields.
+    /// Number is written to the field it was read from, or integer field if it is numeric.
+    fn write_to(
+        &self,
+        record: &mut Record,
+        report_field: &str,
+        integer_field: &str,
+        string_field: &str,
+    ) {
+        set_optional_field(record, report_field, self.report.as_ref());
+        let Some(number) = &self.number else {
+            record.remove_field(integer_field);
+            record.remove_field(string_field);
+            return;
+        };
+
+        let is_integer = !number.is_empty() && number.bytes().all(|c| c.is_ascii_digit());
+        let has_string = record.field(string_field).is_some();
+        let has_integer = record.field(integer_field).is_some();
+        if is_integer && (has_integer || !has_string) {
+            record.set_field(integer_field, number.as_str());
+            if has_string {
+                record.set_field(string_field, number.as_str());
+            }
+        } else {
+            record.remove_field(integer_field);
+            record.set_field(string_field, number.as_str());
+        }
+    }
+
+    /// Parses report and number (joined by a space) with the template.
+    pub fn parse_with(&self, template: &ExchangeTemplate) -> Result<ParsedExchange, ExchangeError> {
+        let text = [self.report.as_deref(), self.number.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        template.parse(&text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QsoExchanges {
     pub sent: QsoExchange,
     pub received: QsoExchange,
@@ -32,4 +95,11 @@ impl QsoExchanges {
         };
         QsoExchanges { sent, received }
     }
+
+    /// Writes back into ADIF fields.
+    pub fn write_to(&self, record: &mut Record) {
+        self.sent.write_to(record, "RST_SENT", "STX", "STX_STRING");
+        self.received
+            .write_to(record, "RST_RCVD", "SRX", "SRX_STRING");
+    }
 }
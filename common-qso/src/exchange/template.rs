@@ -0,0 +1,512 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use callfind::japan::JarlAreaKind;
+use compact_str::{CompactString, ToCompactString};
+use thiserror::Error as ThisError;
+
+use crate::report::Report;
+
+/// Kind of a part which composes contest exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExchangePart {
+    /// RS or RST report.
+    Report,
+
+    /// Serial number.
+    Serial,
+
+    /// JARL prefecture, Hokkaido subprefecture, city (JCC), gun (JCG) or ward number.
+    JarlArea,
+
+    /// JARL power code (`P`/`L`/`M`/`H`).
+    Power,
+
+    /// CQ zone.
+    CqZone,
+
+    /// ITU zone.
+    ItuZone,
+
+    /// Arbitrary text without whitespaces.
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JarlArea {
+    pub kind: JarlAreaKind,
+    pub code: CompactString,
+}
+
+impl JarlArea {
+    /// Returns the JARL prefecture number this area belongs to.
+    /// Hokkaido subprefectures are treated as prefecture `01`.
+    /// Returns `None` if the code does not start with two digits.
+    pub fn prefecture(&self) -> Option<u8> {
+        match self.kind {
+            JarlAreaKind::Subprefecture => Some(1),
+            _ => self.code.get(..2)?.parse().ok(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PowerCode {
+    Qrp,
+    Low,
+    Middle,
+    High,
+}
+
+impl Display for PowerCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PowerCode::Qrp => write!(f, "P"),
+            PowerCode::Low => write!(f, "L"),
+            PowerCode::Middle => write!(f, "M"),
+            PowerCode::High => write!(f, "H"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExchangeComponent {
+    Report(Report),
+    Serial(u32),
+    JarlArea(JarlArea),
+    Power(PowerCode),
+    CqZone(u8),
+    ItuZone(u8),
+    Text(CompactString),
+}
+
+impl ExchangeComponent {
+    pub fn part(&self) -> ExchangePart {
+        match self {
+            ExchangeComponent::Report(_) => ExchangePart::Report,
+            ExchangeComponent::Serial(_) => ExchangePart::Serial,
+            ExchangeComponent::JarlArea(_) => ExchangePart::JarlArea,
+            ExchangeComponent::Power(_) => ExchangePart::Power,
+            ExchangeComponent::CqZone(_) => ExchangePart::CqZone,
+            ExchangeComponent::ItuZone(_) => ExchangePart::ItuZone,
+            ExchangeComponent::Text(_) => ExchangePart::Text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum ExchangeError {
+    #[error("unknown exchange part: {0}")]
+    UnknownPart(CompactString),
+
+    #[error("{0} not found")]
+    Missing(ExchangePart),
+
+    #[error("invalid {part}: {value}")]
+    Invalid {
+        part: ExchangePart,
+        value: CompactString,
+    },
+
+    #[error("unexpected trailing text: {0}")]
+    Trailing(CompactString),
+}
+
+impl ExchangePart {
+    /// Enumerates lengths of syntactically acceptable prefixes, longest first.
+    fn candidates(&self, text: &str) -> Vec<usize> {
+        let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+        let lengths: &[usize] = match self {
+            ExchangePart::Report => return Self::report_candidates(text, digits),
+            ExchangePart::Serial => &[6, 5, 4, 3, 2, 1],
+            ExchangePart::JarlArea => &[6, 5, 4, 3, 2],
+            ExchangePart::CqZone | ExchangePart::ItuZone => &[2, 1],
+            ExchangePart::Power => {
+                return match text.bytes().next() {
+                    Some(b'P' | b'L' | b'M' | b'H' | b'p' | b'l' | b'm' | b'h') => vec![1],
+                    _ => vec![],
+                };
+            }
+            ExchangePart::Text => {
+                let length = text.find(char::is_whitespace).unwrap_or(text.len());
+                return if length > 0 { vec![length] } else { vec![] };
+            }
+        };
+        lengths.iter().copied().filter(|&l| l <= digits).collect()
+    }
+
+    /// Counts `R` and sign of decibel reports, or suffix letter of RST reports.
+    fn report_candidates(text: &str, digits: usize) -> Vec<usize> {
+        let roger = usize::from(text.starts_with(['R', 'r']));
+        let signed = roger + usize::from(text[roger..].starts_with(['+', '-']));
+        if signed > 0 {
+            let digits = text[signed..]
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count();
+            return [2, 1]
+                .into_iter()
+                .filter(|&l| l <= digits)
+                .map(|l| signed + l)
+                .collect();
+        }
+
+        if digits >= 3 && text[3..].starts_with(['X', 'x', 'C', 'c', 'K', 'k', 'A', 'a']) {
+            return vec![4, 3, 2];
+        }
+        [3, 2].into_iter().filter(|&l| l <= digits).collect()
+    }
+
+    fn convert(&self, value: &str) -> Result<ExchangeComponent, ExchangeError> {
+        let invalid = || ExchangeError::Invalid {
+            part: *self,
+            value: value.to_compact_string(),
+        };
+
+        match self {
+            ExchangePart::Report => value
+                .parse()
+                .map(ExchangeComponent::Report)
+                .map_err(|_| invalid()),
+            ExchangePart::Serial => value
+                .parse()
+                .map(ExchangeComponent::Serial)
+                .map_err(|_| invalid()),
+            ExchangePart::JarlArea => {
+                let kind = match value.len() {
+                    2 => JarlAreaKind::Prefecture,
+                    3 => JarlAreaKind::Subprefecture,
+                    4 => JarlAreaKind::City,
+                    5 => JarlAreaKind::Gun,
+                    6 => JarlAreaKind::Ward,
+                    _ => return Err(invalid()),
+                };
+                let valid = match kind {
+                    JarlAreaKind::Subprefecture => matches!(value.parse(), Ok(101..=114)),
+                    _ => matches!(value[..2].parse(), Ok(1..=48)),
+                };
+                valid
+                    .then(|| {
+                        ExchangeComponent::JarlArea(JarlArea {
+                            kind,
+                            code: value.to_compact_string(),
+                        })
+                    })
+                    .ok_or_else(invalid)
+            }
+            ExchangePart::Power => match value {
+                "P" | "p" => Ok(ExchangeComponent::Power(PowerCode::Qrp)),
+                "L" | "l" => Ok(ExchangeComponent::Power(PowerCode::Low)),
+                "M" | "m" => Ok(ExchangeComponent::Power(PowerCode::Middle)),
+                "H" | "h" => Ok(ExchangeComponent::Power(PowerCode::High)),
+                _ => Err(invalid()),
+            },
+            ExchangePart::CqZone => match value.parse() {
+                Ok(z @ 1..=40) => Ok(ExchangeComponent::CqZone(z)),
+                _ => Err(invalid()),
+            },
+            ExchangePart::ItuZone => match value.parse() {
+                Ok(z @ 1..=90) => Ok(ExchangeComponent::ItuZone(z)),
+                _ => Err(invalid()),
+            },
+            ExchangePart::Text => Ok(ExchangeComponent::Text(value.to_compact_string())),
+        }
+    }
+}
+
+impl FromStr for ExchangePart {
+    type Err = ExchangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "report" | "rst" => Ok(ExchangePart::Report),
+            "serial" => Ok(ExchangePart::Serial),
+            "area" => Ok(ExchangePart::JarlArea),
+            "power" => Ok(ExchangePart::Power),
+            "cq_zone" => Ok(ExchangePart::CqZone),
+            "itu_zone" => Ok(ExchangePart::ItuZone),
+            "text" => Ok(ExchangePart::Text),
+            _ => Err(ExchangeError::UnknownPart(s.to_compact_string())),
+        }
+    }
+}
+
+impl Display for ExchangePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ExchangePart::Report => write!(f, "report"),
+            ExchangePart::Serial => write!(f, "serial"),
+            ExchangePart::JarlArea => write!(f, "area"),
+            ExchangePart::Power => write!(f, "power"),
+            ExchangePart::CqZone => write!(f, "cq_zone"),
+            ExchangePart::ItuZone => write!(f, "itu_zone"),
+            ExchangePart::Text => write!(f, "text"),
+        }
+    }
+}
+
+/// Describes the structure of contest exchange.
+///
+/// Written as whitespace-separated part names such as `"report area power"`.
+/// A part name suffixed with `?` is optional.
+/// Parts may be written either concatenated (`59 13M`) or separated by whitespaces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExchangeTemplate {
+    parts: Vec<(ExchangePart, bool)>,
+}
+
+impl ExchangeTemplate {
+    pub fn new(parts: impl IntoIterator<Item = (ExchangePart, bool)>) -> ExchangeTemplate {
+        ExchangeTemplate {
+            parts: parts.into_iter().collect(),
+        }
+    }
+
+    pub fn parts(&self) -> &[(ExchangePart, bool)] {
+        &self.parts
+    }
+
+    pub fn parse(&self, text: &str) -> Result<ParsedExchange, ExchangeError> {
+        let mut components = vec![];
+        let mut furthest = None;
+        if self.parse_from(text.trim(), 0, &mut components, &mut furthest) {
+            Ok(ParsedExchange(components))
+        } else {
+            Err(furthest.map(|(_, e)| e).unwrap_or(ExchangeError::Missing(
+                self.parts.first().map_or(ExchangePart::Text, |p| p.0),
+            )))
+        }
+    }
+
+    /// Backtracks over candidates, longer ones first.
+    /// Keeps the error found at the furthest position, preferring ones other than trailing text.
+    fn parse_from(
+        &self,
+        text: &str,
+        part_index: usize,
+        components: &mut Vec<ExchangeComponent>,
+        furthest: &mut Option<(usize, ExchangeError)>,
+    ) -> bool {
+        let rest = text.trim_start();
+        let position = text.len() - rest.len();
+        let mut record_error = |offset: usize, error: ExchangeError| {
+            let rank = |e: &ExchangeError| !matches!(e, ExchangeError::Trailing(_));
+            if furthest
+                .as_ref()
+                .is_none_or(|(p, e)| (rank(e), *p) < (rank(&error), offset))
+            {
+                *furthest = Some((offset, error));
+            }
+        };
+
+        let Some(&(part, optional)) = self.parts.get(part_index) else {
+            if rest.is_empty() {
+                return true;
+            }
+            record_error(position, ExchangeError::Trailing(rest.to_compact_string()));
+            return false;
+        };
+
+        let candidates = part.candidates(rest);
+        if candidates.is_empty() && !optional {
+            record_error(position, ExchangeError::Missing(part));
+        }
+        for length in candidates {
+            match part.convert(&rest[..length]) {
+                Ok(component) => {
+                    components.push(component);
+                    let mut inner_furthest = None;
+                    if self.parse_from(
+                        &rest[length..],
+                        part_index + 1,
+                        components,
+                        &mut inner_furthest,
+                    ) {
+                        return true;
+                    }
+                    components.pop();
+                    if let Some((p, e)) = inner_furthest {
+                        record_error(position + length + p, e);
+                    }
+                }
+                Err(e) => record_error(position, e),
+            }
+        }
+
+        if optional {
+            let mut inner_furthest = None;
+            if self.parse_from(rest, part_index + 1, components, &mut inner_furthest) {
+                return true;
+            }
+            if let Some((p, e)) = inner_furthest {
+                record_error(position + p, e);
+            }
+        }
+        false
+    }
+}
+
+impl FromStr for ExchangeTemplate {
+    type Err = ExchangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Result<Vec<_>, _> = s
+            .split_whitespace()
+            .map(|p| match p.strip_suffix('?') {
+                Some(name) => Ok((name.parse()?, true)),
+                None => Ok((p.parse()?, false)),
+            })
+            .collect();
+        Ok(ExchangeTemplate { parts: parts? })
+    }
+}
+
+impl Display for ExchangeTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (i, (part, optional)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{part}{}", if *optional { "?" } else { "" })?;
+        }
+        Ok(())
+    }
+}
+
+/// Typed components parsed by `ExchangeTemplate`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedExchange(pub Vec<ExchangeComponent>);
+
+impl ParsedExchange {
+    pub fn components(&self) -> &[ExchangeComponent] {
+        &self.0
+    }
+
+    pub fn report(&self) -> Option<Report> {
+        self.0.iter().find_map(|c| match c {
+            ExchangeComponent::Report(r) => Some(*r),
+            _ => None,
+        })
+    }
+
+    pub fn serial(&self) -> Option<u32> {
+        self.0.iter().find_map(|c| match c {
+            ExchangeComponent::Serial(s) => Some(*s),
+            _ => None,
+        })
+    }
+
+    pub fn area(&self) -> Option<&JarlArea> {
+        self.0.iter().find_map(|c| match c {
+            ExchangeComponent::JarlArea(a) => Some(a),
+            _ => None,
+        })
+    }
+
+    pub fn power(&self) -> Option<PowerCode> {
+        self.0.iter().find_map(|c| match c {
+            ExchangeComponent::Power(p) => Some(*p),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use callfind::japan::JarlAreaKind;
+
+    use super::{ExchangeError, ExchangePart, ExchangeTemplate, JarlArea, PowerCode};
+
+    #[test]
+    fn parses_concatenated_exchange() {
+        let template: ExchangeTemplate = "report area power".parse().expect("valid template");
+
+        let parsed = template.parse("59913M").expect("valid exchange");
+        assert_eq!(
+            parsed.report().map(|r| r.to_string()).as_deref(),
+            Some("599")
+        );
+        assert_eq!(parsed.area().and_then(|a| a.prefecture()), Some(13));
+        assert_eq!(parsed.power(), Some(PowerCode::Middle));
+
+        let parsed = template.parse("59 1001H").expect("valid exchange");
+        assert_eq!(parsed.report().and_then(|r| r.tone()), None);
+        assert_eq!(parsed.area().map(|a| a.kind), Some(JarlAreaKind::City));
+    }
+
+    #[test]
+    fn parses_suffixed_and_decibel_reports() {
+        let template: ExchangeTemplate = "report area".parse().expect("valid template");
+        let parsed = template.parse("599K13").expect("valid exchange");
+        assert_eq!(
+            parsed.report().map(|r| r.to_string()).as_deref(),
+            Some("599K")
+        );
+        assert_eq!(parsed.area().and_then(|a| a.prefecture()), Some(13));
+
+        let parsed = template.parse("-1213").expect("valid exchange");
+        assert_eq!(
+            parsed.report().map(|r| r.to_string()).as_deref(),
+            Some("-12")
+        );
+        let parsed = template.parse("R+05 1001").expect("valid exchange");
+        assert_eq!(
+            parsed.report().map(|r| r.to_string()).as_deref(),
+            Some("R+05")
+        );
+    }
+
+    #[test]
+    fn accepts_ogasawara() {
+        let template: ExchangeTemplate = "report area".parse().expect("valid template");
+        let parsed = template.parse("599 48").expect("valid exchange");
+        assert_eq!(parsed.area().and_then(|a| a.prefecture()), Some(48));
+        assert!(template.parse("599 49").is_err());
+    }
+
+    #[test]
+    fn returns_no_prefecture_for_malformed_code() {
+        let area = JarlArea {
+            kind: JarlAreaKind::City,
+            code: "X".into(),
+        };
+        assert_eq!(area.prefecture(), None);
+    }
+
+    #[test]
+    fn parses_optional_parts() {
+        let template: ExchangeTemplate = "report serial power?".parse().expect("valid template");
+        let parsed = template.parse("599 001").expect("valid exchange");
+        assert_eq!(parsed.serial(), Some(1));
+        assert_eq!(parsed.power(), None);
+    }
+
+    #[test]
+    fn reports_errors() {
+        let template: ExchangeTemplate = "report cq_zone".parse().expect("valid template");
+        assert_eq!(
+            template.parse("599 41"),
+            Err(ExchangeError::Invalid {
+                part: ExchangePart::CqZone,
+                value: "41".into()
+            })
+        );
+        assert_eq!(
+            "report power"
+                .parse::<ExchangeTemplate>()
+                .expect("valid template")
+                .parse("599"),
+            Err(ExchangeError::Missing(ExchangePart::Power))
+        );
+        assert_eq!(
+            template.parse("599 25 X"),
+            Err(ExchangeError::Trailing("X".into()))
+        );
+        assert!(matches!(
+            "report unknown".parse::<ExchangeTemplate>(),
+            Err(ExchangeError::UnknownPart(_))
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use std::io::{Result as IoResult, Write};
+
+use crate::qso::Qso;
+
+/// JSON Schema of each line written by `write_json_lines`.
+pub const QSO_JSON_SCHEMA: &str = include_str!("../schema/qso.schema.json");
+
+/// Writes QSOs in JSON Lines, one object per line.
+/// Field names follow `QSO_JSON_SCHEMA`; diagnostics are not included.
+pub fn write_json_lines<'a>(
+    mut writer: impl Write,
+    qsos: impl IntoIterator<Item = &'a Qso>,
+) -> IoResult<()> {
+    for qso in qsos {
+        serde_json::to_writer(&mut writer, qso)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use adif_reader::{LengthMode, read_adi};
+    use serde_json::{Map, Value};
+    use time::UtcOffset;
+
+    use super::{QSO_JSON_SCHEMA, write_json_lines};
+    use crate::qso::{Qso, Strictness};
+
+    fn parse_qso(adi: &str) -> Qso {
+        let document = read_adi(adi, LengthMode::Bytes).expect("valid ADI");
+        Qso::new(&document.records()[0], UtcOffset::UTC, Strictness::Strict).expect("valid QSO")
+    }
+
+    fn keys(object: &Map<String, Value>) -> BTreeSet<&str> {
+        object.keys().map(|k| k.as_str()).collect()
+    }
+
+    /// Checks that every object in `value` has exactly the properties declared in `schema`.
+    fn assert_matches_schema(value: &Value, schema: &Value, root: &Value) {
+        let schema = match schema.get("anyOf").and_then(Value::as_array) {
+            Some(variants) => &variants[0],
+            None => schema,
+        };
+        let schema = match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => {
+                let name = reference.trim_start_matches("#/$defs/");
+                &root["$defs"][name]
+            }
+            None => schema,
+        };
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        let properties = schema["properties"]
+            .as_object()
+            .expect("object schema must have properties");
+        assert_eq!(keys(object), keys(properties));
+        for (key, child) in object {
+            assert_matches_schema(child, &properties[key], root);
+        }
+    }
+
+    #[test]
+    fn writes_json_lines() {
+        let qso = parse_qso(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:5>JA1ZZZ<FREQ:5>7.010<RST_SENT:3>599<STX:3>001<CONT:2>AS<LOTW_QSLRDATE:8>20250110<eor>",
+        );
+        let mut output = vec![];
+        write_json_lines(&mut output, [&qso, &qso]).expect("writable");
+
+        let text = String::from_utf8(output).expect("UTF-8");
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let value: Value = serde_json::from_str(lines[0]).expect("valid JSON");
+        assert_eq!(value["record"]["datetime"], "2025-01-02T12:34:56Z");
+        assert_eq!(value["record"]["band"], "40m");
+        assert_eq!(value["exchanges"]["sent"]["number"], "001");
+        assert_eq!(value["station"]["continent"], "AS");
+        assert_eq!(value["qsl"]["lotw"]["received_date"], "2025-01-10");
+
+        let restored: Qso = serde_json::from_value(value).expect("deserializable");
+        assert_eq!(restored.record, qso.record);
+        assert_eq!(restored.qsl, qso.qsl);
+    }
+
+    #[test]
+    fn schema_matches_output() {
+        let schema: Value = serde_json::from_str(QSO_JSON_SCHEMA).expect("valid schema");
+        let validator = jsonschema::validator_for(&schema).expect("valid JSON Schema");
+        let qsos = [
+            parse_qso(
+                "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:2>2m<MODE:2>FM<CALL:4>K1AB<FREQ:7>145.850<PROP_MODE:3>SAT<SAT_NAME:5>SO-50<BAND_RX:4>70cm<eor>",
+            ),
+            parse_qso(
+                "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<RST_SENT:3>599<SRX_STRING:3>13M<GRIDSQUARE:6>PM95vq<VUCC_GRIDS:9>PM95,PM96<LAT:11>N035 41.022<LON:11>E139 46.020<CQZ:2>25<CONT:2>AS<POTA_REF:7>JA-0001<MY_GRIDSQUARE:4>PM95<QSL_SENT:1>Y<QSLSDATE:8>20250103<eor>",
+            ),
+        ];
+        let mut output = vec![];
+        write_json_lines(&mut output, &qsos).expect("writable");
+
+        for line in String::from_utf8(output).expect("UTF-8").lines() {
+            let value: Value = serde_json::from_str(line).expect("valid JSON");
+            let errors: Vec<_> = validator
+                .iter_errors(&value)
+                .map(|e| format!("{}: {e}", e.instance_path()))
+                .collect();
+            assert!(errors.is_empty(), "{errors:#?}");
+            assert_matches_schema(&value, &schema, &schema);
+        }
+
+        let mut broken = serde_json::to_value(&qsos[1]).expect("serializable");
+        broken["station"]["cq_zone"] = Value::from(41);
+        assert!(!validator.is_valid(&broken));
+    }
+}
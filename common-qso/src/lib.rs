@@ -1,18 +1,84 @@
 pub mod band;
+pub mod bandplan;
+pub mod crosscheck;
+pub mod diagnostic;
 pub mod error;
 pub mod exchange;
+pub mod export;
+pub mod matching;
+pub mod mode;
+pub mod propagation;
 pub mod qsl;
+pub mod qso;
 pub mod record;
+pub mod reference;
+pub mod report;
+pub mod station;
+
+mod serde_format;
+
+use std::{fmt::Display, str::FromStr};
 
 use adif_reader::document::Record;
+use time::{Date, format_description::BorrowedFormatItem, macros::format_description};
 
 use crate::error::QsoError;
 
-fn get_required_field<'a>(record: &'a Record, field: &'static str) -> Result<&'a str, QsoError> {
-    record.field(field).ok_or(QsoError::MissingAdifField(field))
-}
+const ADIF_DATE: &[BorrowedFormatItem<'_>] = format_description!(
+    "[year repr:full padding:zero][month repr:numerical padding:zero][day padding:zero]"
+);
+const ADIF_TIME: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour repr:24 padding:zero][minute padding:zero][second padding:zero]");
 
 fn get_optional_field_oneof<'a>(record: &'a Record, fields: &[&'static str]) -> Option<&'a str> {
     assert!(!fields.is_empty(), "fields must be specified");
     fields.iter().find_map(|f| record.field(*f))
 }
+
+fn parse_field<T>(value: &str) -> Result<T, QsoError>
+where
+    T: FromStr,
+    QsoError: From<T::Err>,
+{
+    Ok(value.parse()?)
+}
+
+fn set_optional_field(record: &mut Record, field: &str, value: Option<impl Display>) {
+    match value {
+        Some(v) => record.set_field(field, v.to_string()),
+        None => record.remove_field(field),
+    };
+}
+
+/// Sets text field, using `intl_field` for non-ASCII text.
+/// ASCII text is also written to `intl_field` if it exists, since it takes precedence on read.
+/// Changing non-ASCII text removes `field`, which held an ASCII rendering of the old text.
+fn set_optional_intl_field(
+    record: &mut Record,
+    field: &str,
+    intl_field: &str,
+    value: Option<&str>,
+) {
+    match value {
+        Some(v) if v.is_ascii() => {
+            record.set_field(field, v);
+            if record.field(intl_field).is_some() {
+                record.set_field(intl_field, v);
+            }
+        }
+        Some(v) => {
+            if record.field(intl_field) != Some(v) {
+                record.remove_field(field);
+            }
+            record.set_field(intl_field, v);
+        }
+        None => {
+            record.remove_field(field);
+            record.remove_field(intl_field);
+        }
+    }
+}
+
+fn format_date(date: Date) -> String {
+    date.format(ADIF_DATE).expect("date must be formattable")
+}
@@ -0,0 +1,258 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use callfind::callsign::base_callsign;
+use compact_str::{CompactString, ToCompactString};
+use time::Duration;
+
+use crate::{band::Band, mode::ModeCategory, qso::Qso, record::QsoRecord};
+
+/// Which properties identify the same QSO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchIdentity {
+    /// Compares base callsigns (`JA1ZZZ/1` matches `JA1ZZZ`) instead of exact ones.
+    pub normalize_call: bool,
+
+    pub band: bool,
+    pub mode_category: bool,
+
+    /// Maximum difference of QSO datetime.
+    pub time_window: Duration,
+}
+
+impl Default for MatchIdentity {
+    fn default() -> MatchIdentity {
+        MatchIdentity {
+            normalize_call: true,
+            band: true,
+            mode_category: true,
+            time_window: Duration::minutes(10),
+        }
+    }
+}
+
+/// Why two QSOs matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchReason {
+    /// Callsigns are identical.
+    SameCall(CompactString),
+
+    /// Callsigns differ but share the base callsign.
+    SameBaseCall {
+        left: CompactString,
+        right: CompactString,
+        base: CompactString,
+    },
+
+    SameBand(Band),
+    SameModeCategory(ModeCategory),
+
+    /// Datetime differs by the duration, which is within the window.
+    WithinTimeWindow(Duration),
+}
+
+impl Display for MatchReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            MatchReason::SameCall(call) => write!(f, "same call {call}"),
+            MatchReason::SameBaseCall { left, right, base } => {
+                write!(f, "{left} and {right} share base call {base}")
+            }
+            MatchReason::SameBand(band) => write!(f, "same band {band}"),
+            MatchReason::SameModeCategory(category) => write!(f, "same {category} mode"),
+            MatchReason::WithinTimeWindow(difference) => {
+                write!(f, "{} seconds apart", difference.whole_seconds())
+            }
+        }
+    }
+}
+
+/// A pair of matched QSOs, referenced by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QsoMatch {
+    pub left: usize,
+    pub right: usize,
+    pub reasons: Vec<MatchReason>,
+}
+
+impl MatchIdentity {
+    /// Returns reasons if two QSOs are identified as the same one.
+    pub fn explain(&self, left: &QsoRecord, right: &QsoRecord) -> Option<Vec<MatchReason>> {
+        let mut reasons = vec![];
+
+        if left.call.eq_ignore_ascii_case(&right.call) {
+            reasons.push(MatchReason::SameCall(left.call.to_ascii_uppercase()));
+        } else if self.normalize_call {
+            let base = base_callsign(&left.call);
+            if base != base_callsign(&right.call) {
+                return None;
+            }
+            reasons.push(MatchReason::SameBaseCall {
+                left: left.call.clone(),
+                right: right.call.clone(),
+                base: base.to_compact_string(),
+            });
+        } else {
+            return None;
+        }
+
+        if self.band {
+            if left.band != right.band {
+                return None;
+            }
+            reasons.push(MatchReason::SameBand(left.band));
+        }
+
+        if self.mode_category {
+            let category = left.mode_category();
+            if category != right.mode_category() {
+                return None;
+            }
+            reasons.push(MatchReason::SameModeCategory(category));
+        }
+
+        let difference = (left.datetime - right.datetime).abs();
+        if difference > self.time_window {
+            return None;
+        }
+        reasons.push(MatchReason::WithinTimeWindow(difference));
+
+        Some(reasons)
+    }
+
+    /// Finds duplicate pairs within a log. `left` of each pair is the earlier index.
+    pub fn find_duplicates<R: AsRef<QsoRecord>>(&self, log: &[R]) -> Vec<QsoMatch> {
+        let sorted = sorted_by_time(log);
+        let mut matches = vec![];
+        for (i, &left) in sorted.iter().enumerate() {
+            let left_record = log[left].as_ref();
+            for &right in &sorted[i + 1..] {
+                let right_record = log[right].as_ref();
+                if right_record.datetime - left_record.datetime > self.time_window {
+                    break;
+                }
+                if let Some(reasons) = self.explain(left_record, right_record) {
+                    matches.push(QsoMatch {
+                        left: left.min(right),
+                        right: left.max(right),
+                        reasons,
+                    });
+                }
+            }
+        }
+        matches.sort_by_key(|m| (m.left, m.right));
+        matches
+    }
+
+    /// Finds pairs across two logs which can be merged into one QSO.
+    /// A QSO may appear in several pairs if more than one candidate exists.
+    pub fn merge_candidates<L: AsRef<QsoRecord>, R: AsRef<QsoRecord>>(
+        &self,
+        left_log: &[L],
+        right_log: &[R],
+    ) -> Vec<QsoMatch> {
+        let sorted_right = sorted_by_time(right_log);
+        let mut matches = vec![];
+        for (left, left_record) in left_log.iter().enumerate() {
+            let left_record = left_record.as_ref();
+            let start = sorted_right.partition_point(|&r| {
+                left_record.datetime - right_log[r].as_ref().datetime > self.time_window
+            });
+            for &right in &sorted_right[start..] {
+                let right_record = right_log[right].as_ref();
+                if right_record.datetime - left_record.datetime > self.time_window {
+                    break;
+                }
+                if let Some(reasons) = self.explain(left_record, right_record) {
+                    matches.push(QsoMatch {
+                        left,
+                        right,
+                        reasons,
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+fn sorted_by_time<R: AsRef<QsoRecord>>(log: &[R]) -> Vec<usize> {
+    let mut indices: Vec<_> = (0..log.len()).collect();
+    indices.sort_by_key(|&i| log[i].as_ref().datetime);
+    indices
+}
+
+impl AsRef<QsoRecord> for QsoRecord {
+    fn as_ref(&self) -> &QsoRecord {
+        self
+    }
+}
+
+impl AsRef<QsoRecord> for Qso {
+    fn as_ref(&self) -> &QsoRecord {
+        &self.record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+    use time::{Duration, UtcOffset};
+
+    use super::{MatchIdentity, MatchReason};
+    use crate::record::QsoRecord;
+
+    fn records(adi: &str) -> Vec<QsoRecord> {
+        let adif = read_adi(adi, LengthMode::Bytes).expect("valid ADI");
+        adif.records()
+            .iter()
+            .map(|r| QsoRecord::new(r, UtcOffset::UTC).expect("valid QSO"))
+            .collect()
+    }
+
+    const LOG: &str = concat!(
+        "<QSO_DATE:8>20250102<TIME_ON:6>120000<BAND:3>40m<MODE:2>CW<CALL:6>JA1ZZZ<FREQ:5>7.010<eor>",
+        "<QSO_DATE:8>20250102<TIME_ON:6>120500<BAND:3>40m<MODE:3>SSB<CALL:6>JA1ZZZ<FREQ:5>7.100<eor>",
+        "<QSO_DATE:8>20250102<TIME_ON:6>120300<BAND:3>40m<MODE:2>CW<CALL:8>JA1ZZZ/1<FREQ:5>7.012<eor>",
+        "<QSO_DATE:8>20250102<TIME_ON:6>140000<BAND:3>40m<MODE:2>CW<CALL:6>JA1ZZZ<FREQ:5>7.010<eor>",
+    );
+
+    #[test]
+    fn finds_duplicates() {
+        let log = records(LOG);
+        let matches = MatchIdentity::default().find_duplicates(&log);
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].left, matches[0].right), (0, 2));
+        assert!(matches!(
+            &matches[0].reasons[0],
+            MatchReason::SameBaseCall { base, .. } if base == "JA1ZZZ"
+        ));
+        assert_eq!(
+            matches[0].reasons.last(),
+            Some(&MatchReason::WithinTimeWindow(Duration::minutes(3)))
+        );
+
+        let identity = MatchIdentity {
+            mode_category: false,
+            normalize_call: false,
+            ..Default::default()
+        };
+        let matches = identity.find_duplicates(&log);
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].left, matches[0].right), (0, 1));
+    }
+
+    #[test]
+    fn finds_merge_candidates() {
+        let left = records(LOG);
+        let right = records(
+            "<QSO_DATE:8>20250102<TIME_ON:6>135800<BAND:3>40m<MODE:2>CW<CALL:6>ja1zzz<FREQ:5>7.010<eor>",
+        );
+        let matches = MatchIdentity::default().merge_candidates(&left, &right);
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].left, matches[0].right), (3, 0));
+        assert_eq!(
+            matches[0].reasons[0],
+            MatchReason::SameCall("JA1ZZZ".into())
+        );
+    }
+}
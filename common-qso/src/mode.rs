@@ -0,0 +1,92 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+/// Category of ADIF mode, which determines the form of signal reports.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum ModeCategory {
+    /// Voice modes, reported in RS.
+    Phone,
+
+    /// CW, reported in RST.
+    Cw,
+
+    /// Keyboard data modes such as RTTY and PSK, reported in RST.
+    Data,
+
+    /// Weak signal digital modes such as FT8, reported in dB.
+    WeakSignal,
+
+    /// Image modes (SSTV, FAX, ATV).
+    Image,
+
+    Other,
+}
+
+impl ModeCategory {
+    /// Determines category from ADIF `MODE` and `SUBMODE`.
+    pub fn from_adif(mode: &str, submode: Option<&str>) -> ModeCategory {
+        let mode = mode.to_ascii_uppercase();
+        let submode = submode.map(|s| s.to_ascii_uppercase());
+        match (mode.as_str(), submode.as_deref()) {
+            ("MFSK", Some("FT4" | "FST4" | "FST4W" | "Q65" | "JS8" | "FT2")) => {
+                ModeCategory::WeakSignal
+            }
+            (
+                "FT8" | "JT65" | "JT9" | "JT4" | "JT6M" | "JT44" | "MSK144" | "ISCAT" | "WSPR"
+                | "Q65" | "FT4" | "FST4",
+                _,
+            ) => ModeCategory::WeakSignal,
+            ("SSB" | "AM" | "FM" | "DIGITALVOICE" | "C4FM" | "DSTAR", _) => ModeCategory::Phone,
+            ("CW", _) => ModeCategory::Cw,
+            ("SSTV" | "FAX" | "ATV", _) => ModeCategory::Image,
+            (
+                "RTTY" | "PSK" | "MFSK" | "OLIVIA" | "CONTESTI" | "HELL" | "DOMINO" | "THOR"
+                | "THRB" | "MT63" | "PAC" | "PKT" | "PAX" | "ARDOP" | "VARA" | "ROS" | "RTTYM"
+                | "CHIP" | "CLO" | "FSK441" | "FSK31" | "QRA64" | "OPERA" | "PSK2K" | "V4" | "VOI"
+                | "WINMOR" | "T10" | "DYNAMIC",
+                _,
+            ) => ModeCategory::Data,
+            _ => ModeCategory::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("invalid mode category")]
+pub struct InvalidModeCategory;
+
+impl FromStr for ModeCategory {
+    type Err = InvalidModeCategory;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "phone" => Ok(ModeCategory::Phone),
+            "cw" => Ok(ModeCategory::Cw),
+            "data" => Ok(ModeCategory::Data),
+            "weak_signal" => Ok(ModeCategory::WeakSignal),
+            "image" => Ok(ModeCategory::Image),
+            "other" => Ok(ModeCategory::Other),
+            _ => Err(InvalidModeCategory),
+        }
+    }
+}
+
+impl Display for ModeCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ModeCategory::Phone => write!(f, "phone"),
+            ModeCategory::Cw => write!(f, "cw"),
+            ModeCategory::Data => write!(f, "data"),
+            ModeCategory::WeakSignal => write!(f, "weak_signal"),
+            ModeCategory::Image => write!(f, "image"),
+            ModeCategory::Other => write!(f, "other"),
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use adif_reader::document::Record;
+use compact_str::{CompactString, ToCompactString};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+use crate::{band::Band, diagnostic::FieldReader, record::QsoRecord, set_optional_field};
+
+/// ADIF `Propagation_Mode` enumeration.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum Propagation {
+    /// `AS`: aircraft scatter.
+    AircraftScatter,
+
+    /// `AUE`: aurora-E.
+    AuroraE,
+
+    /// `AUR`: aurora.
+    Aurora,
+
+    /// `BS`: back scatter.
+    BackScatter,
+
+    /// `ECH`: EchoLink.
+    EchoLink,
+
+    /// `EME`: Earth-Moon-Earth.
+    Eme,
+
+    /// `ES`: sporadic E.
+    SporadicE,
+
+    /// `F2`: F2 reflection.
+    F2,
+
+    /// `FAI`: field aligned irregularities.
+    Fai,
+
+    /// `GWAVE`: ground wave.
+    GroundWave,
+
+    /// `INTERNET`: internet-assisted.
+    Internet,
+
+    /// `ION`: ionoscatter.
+    Ionoscatter,
+
+    /// `IRL`: IRLP.
+    Irlp,
+
+    /// `LOS`: line of sight.
+    LineOfSight,
+
+    /// `MS`: meteor scatter.
+    MeteorScatter,
+
+    /// `RPT`: terrestrial or atmospheric repeater or transponder.
+    Repeater,
+
+    /// `RS`: rain scatter.
+    RainScatter,
+
+    /// `SAT`: satellite.
+    Satellite,
+
+    /// `TEP`: trans-equatorial.
+    TransEquatorial,
+
+    /// `TR`: tropospheric ducting.
+    Tropospheric,
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("invalid propagation mode")]
+pub struct InvalidPropagation;
+
+impl Propagation {
+    /// Whether the contact relied on the internet (EchoLink, IRLP and so on).
+    pub fn is_internet_assisted(&self) -> bool {
+        matches!(
+            self,
+            Propagation::EchoLink | Propagation::Internet | Propagation::Irlp
+        )
+    }
+}
+
+impl FromStr for Propagation {
+    type Err = InvalidPropagation;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "AS" => Ok(Propagation::AircraftScatter),
+            "AUE" => Ok(Propagation::AuroraE),
+            "AUR" => Ok(Propagation::Aurora),
+            "BS" => Ok(Propagation::BackScatter),
+            "ECH" => Ok(Propagation::EchoLink),
+            "EME" => Ok(Propagation::Eme),
+            "ES" => Ok(Propagation::SporadicE),
+            "F2" => Ok(Propagation::F2),
+            "FAI" => Ok(Propagation::Fai),
+            "GWAVE" => Ok(Propagation::GroundWave),
+            "INTERNET" => Ok(Propagation::Internet),
+            "ION" => Ok(Propagation::Ionoscatter),
+            "IRL" => Ok(Propagation::Irlp),
+            "LOS" => Ok(Propagation::LineOfSight),
+            "MS" => Ok(Propagation::MeteorScatter),
+            "RPT" => Ok(Propagation::Repeater),
+            "RS" => Ok(Propagation::RainScatter),
+            "SAT" => Ok(Propagation::Satellite),
+            "TEP" => Ok(Propagation::TransEquatorial),
+            "TR" => Ok(Propagation::Tropospheric),
+            _ => Err(InvalidPropagation),
+        }
+    }
+}
+
+impl Display for Propagation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Propagation::AircraftScatter => write!(f, "AS"),
+            Propagation::AuroraE => write!(f, "AUE"),
+            Propagation::Aurora => write!(f, "AUR"),
+            Propagation::BackScatter => write!(f, "BS"),
+            Propagation::EchoLink => write!(f, "ECH"),
+            Propagation::Eme => write!(f, "EME"),
+            Propagation::SporadicE => write!(f, "ES"),
+            Propagation::F2 => write!(f, "F2"),
+            Propagation::Fai => write!(f, "FAI"),
+            Propagation::GroundWave => write!(f, "GWAVE"),
+            Propagation::Internet => write!(f, "INTERNET"),
+            Propagation::Ionoscatter => write!(f, "ION"),
+            Propagation::Irlp => write!(f, "IRL"),
+            Propagation::LineOfSight => write!(f, "LOS"),
+            Propagation::MeteorScatter => write!(f, "MS"),
+            Propagation::Repeater => write!(f, "RPT"),
+            Propagation::RainScatter => write!(f, "RS"),
+            Propagation::Satellite => write!(f, "SAT"),
+            Propagation::TransEquatorial => write!(f, "TEP"),
+            Propagation::Tropospheric => write!(f, "TR"),
+        }
+    }
+}
+
+/// One direction of a satellite contact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SatelliteLink {
+    pub band: Band,
+    pub frequency: Option<CompactString>,
+}
+
+/// Satellite used for a QSO.
+///
+/// Uplink and downlink are owned by `QsoRecord`; see `QsoRecord::uplink` and `QsoRecord::downlink`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SatelliteInfo {
+    /// `SAT_NAME`, such as `SO-50` or `ISS`.
+    pub name: Option<CompactString>,
+
+    /// `SAT_MODE`, such as `U/V` or `V/U`.
+    pub mode: Option<CompactString>,
+}
+
+impl SatelliteInfo {
+    /// Reads satellite info if `PROP_MODE` is `SAT`, or `SAT_NAME` or `SAT_MODE` is present.
+    pub(crate) fn read(reader: &mut FieldReader, record: &QsoRecord) -> Option<SatelliteInfo> {
+        let name = reader.text(&["SAT_NAME"]);
+        let mode = reader.text(&["SAT_MODE"]);
+        if record.propagation != Some(Propagation::Satellite) && name.is_none() && mode.is_none() {
+            return None;
+        }
+
+        Some(SatelliteInfo {
+            name: name.map(|s| s.to_compact_string()),
+            mode: mode.map(|s| s.to_compact_string()),
+        })
+    }
+
+    /// Writes back `SAT_NAME` and `SAT_MODE`.
+    pub fn write_to(&self, record: &mut Record) {
+        set_optional_field(record, "SAT_NAME", self.name.as_ref());
+        set_optional_field(record, "SAT_MODE", self.mode.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+    use time::UtcOffset;
+
+    use super::Propagation;
+    use crate::{
+        band::Band,
+        qso::{Qso, Strictness},
+    };
+
+    #[test]
+    fn parses_propagation() {
+        assert_eq!("eme".parse().ok(), Some(Propagation::Eme));
+        assert_eq!(Propagation::Tropospheric.to_string(), "TR");
+        assert!(Propagation::Irlp.is_internet_assisted());
+        assert!("SKY".parse::<Propagation>().is_err());
+    }
+
+    #[test]
+    fn reads_satellite_info() {
+        let adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:2>2m<MODE:2>FM<CALL:6>JA1XXX<FREQ:7>145.850<PROP_MODE:3>SAT<SAT_NAME:5>SO-50<SAT_MODE:3>V/U<BAND_RX:4>70cm<FREQ_RX:7>436.795<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        let qso = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Strict)
+            .expect("must be accepted");
+
+        let satellite = qso.satellite.expect("satellite QSO");
+        assert_eq!(satellite.name.as_deref(), Some("SO-50"));
+        assert_eq!(qso.record.uplink().band, Band::Meter2);
+        let downlink = qso.record.downlink().expect("downlink exists");
+        assert_eq!(downlink.band, Band::Centimeter70);
+        assert_eq!(downlink.frequency.as_deref(), Some("436.795"));
+    }
+
+    #[test]
+    fn derives_downlink_band() {
+        let mut adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:2>2m<MODE:2>FM<CALL:6>JA1XXX<FREQ:7>145.850<SAT_MODE:3>V/U<FREQ_RX:7>436.795<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let mut qso =
+            Qso::new(record, UtcOffset::UTC, Strictness::Strict).expect("must be accepted");
+        let downlink = qso.record.downlink().expect("downlink exists");
+        assert_eq!(downlink.band, Band::Centimeter70);
+
+        let satellite = qso
+            .satellite
+            .as_ref()
+            .expect("SAT_MODE makes a satellite QSO");
+        assert_eq!(satellite.mode.as_deref(), Some("V/U"));
+        qso.write_to(record);
+        assert_eq!(record.field("SAT_MODE"), Some("V/U"));
+
+        qso.satellite = None;
+        qso.write_to(record);
+        assert_eq!(record.field("SAT_MODE"), None);
+    }
+}
@@ -1,10 +1,20 @@
-use std::str::FromStr;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
 
 use adif_reader::document::Record;
+use compact_str::{CompactString, ToCompactString};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay, serde_as};
+use time::Date;
 
-use crate::error::QsoError;
+use crate::{
+    ADIF_DATE, diagnostic::FieldReader, error::QsoError, format_date, parse_field,
+    serde_format::IsoDate, set_optional_field, set_optional_intl_field,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
 pub enum QslReceiveStatus {
     Invalid,
     Unconfirmed,
@@ -13,22 +23,43 @@ pub enum QslReceiveStatus {
     Verified,
 }
 
+impl QslReceiveStatus {
+    pub fn is_confirmed(&self) -> bool {
+        matches!(
+            self,
+            QslReceiveStatus::Confirmed | QslReceiveStatus::Verified
+        )
+    }
+}
+
 impl FromStr for QslReceiveStatus {
     type Err = QsoError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "I" => Ok(QslReceiveStatus::Invalid),
-            "N" => Ok(QslReceiveStatus::Unconfirmed),
-            "Y" => Ok(QslReceiveStatus::Confirmed),
-            "R" => Ok(QslReceiveStatus::Requested),
-            "V" => Ok(QslReceiveStatus::Verified),
+            "I" | "i" => Ok(QslReceiveStatus::Invalid),
+            "N" | "n" => Ok(QslReceiveStatus::Unconfirmed),
+            "Y" | "y" => Ok(QslReceiveStatus::Confirmed),
+            "R" | "r" => Ok(QslReceiveStatus::Requested),
+            "V" | "v" => Ok(QslReceiveStatus::Verified),
             _ => Err(QsoError::QslParse),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+impl Display for QslReceiveStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QslReceiveStatus::Invalid => write!(f, "I"),
+            QslReceiveStatus::Unconfirmed => write!(f, "N"),
+            QslReceiveStatus::Confirmed => write!(f, "Y"),
+            QslReceiveStatus::Requested => write!(f, "R"),
+            QslReceiveStatus::Verified => write!(f, "V"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
 pub enum QslSendStatus {
     Invalid,
     NotSent,
@@ -42,17 +73,29 @@ impl FromStr for QslSendStatus {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "I" => Ok(QslSendStatus::Invalid),
-            "N" => Ok(QslSendStatus::NotSent),
-            "Y" => Ok(QslSendStatus::Sent),
-            "R" => Ok(QslSendStatus::Requested),
-            "Q" => Ok(QslSendStatus::Queued),
+            "I" | "i" => Ok(QslSendStatus::Invalid),
+            "N" | "n" => Ok(QslSendStatus::NotSent),
+            "Y" | "y" => Ok(QslSendStatus::Sent),
+            "R" | "r" => Ok(QslSendStatus::Requested),
+            "Q" | "q" => Ok(QslSendStatus::Queued),
             _ => Err(QsoError::QslParse),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+impl Display for QslSendStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QslSendStatus::Invalid => write!(f, "I"),
+            QslSendStatus::NotSent => write!(f, "N"),
+            QslSendStatus::Sent => write!(f, "Y"),
+            QslSendStatus::Requested => write!(f, "R"),
+            QslSendStatus::Queued => write!(f, "Q"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
 pub enum QslVia {
     Bureau,
     Direct,
@@ -65,46 +108,284 @@ impl FromStr for QslVia {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "B" => Ok(QslVia::Bureau),
-            "D" => Ok(QslVia::Direct),
-            "E" => Ok(QslVia::Electronic),
-            "M" => Ok(QslVia::Manager),
+            "B" | "b" => Ok(QslVia::Bureau),
+            "D" | "d" => Ok(QslVia::Direct),
+            "E" | "e" => Ok(QslVia::Electronic),
+            "M" | "m" => Ok(QslVia::Manager),
             _ => Err(QsoError::QslParse),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+impl Display for QslVia {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QslVia::Bureau => write!(f, "B"),
+            QslVia::Direct => write!(f, "D"),
+            QslVia::Electronic => write!(f, "E"),
+            QslVia::Manager => write!(f, "M"),
+        }
+    }
+}
+
+/// Upload status for logbook services (Club Log, QRZ.com).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum QsoUploadStatus {
+    Uploaded,
+    NotUploaded,
+    Modified,
+}
+
+impl FromStr for QsoUploadStatus {
+    type Err = QsoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Y" | "y" => Ok(QsoUploadStatus::Uploaded),
+            "N" | "n" => Ok(QsoUploadStatus::NotUploaded),
+            "M" | "m" => Ok(QsoUploadStatus::Modified),
+            _ => Err(QsoError::QslParse),
+        }
+    }
+}
+
+impl Display for QsoUploadStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QsoUploadStatus::Uploaded => write!(f, "Y"),
+            QsoUploadStatus::NotUploaded => write!(f, "N"),
+            QsoUploadStatus::Modified => write!(f, "M"),
+        }
+    }
+}
+
+/// Services which can confirm a QSO.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum QslService {
+    Paper,
+    Lotw,
+    Eqsl,
+}
+
+impl FromStr for QslService {
+    type Err = QsoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "paper" => Ok(QslService::Paper),
+            "lotw" => Ok(QslService::Lotw),
+            "eqsl" => Ok(QslService::Eqsl),
+            _ => Err(QsoError::QslParse),
+        }
+    }
+}
+
+impl Display for QslService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            QslService::Paper => write!(f, "paper"),
+            QslService::Lotw => write!(f, "lotw"),
+            QslService::Eqsl => write!(f, "eqsl"),
+        }
+    }
+}
+
+/// Confirmation status for electronic QSL services (LoTW, eQSL).
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct ServiceQslStatus {
+    pub receive: Option<QslReceiveStatus>,
+    pub send: Option<QslSendStatus>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub received_date: Option<Date>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub sent_date: Option<Date>,
+}
+
+impl ServiceQslStatus {
+    fn read(reader: &mut FieldReader, prefix: &str) -> ServiceQslStatus {
+        ServiceQslStatus {
+            receive: reader.optional(&format!("{prefix}_QSL_RCVD"), parse_field),
+            send: reader.optional(&format!("{prefix}_QSL_SENT"), parse_field),
+            received_date: reader.optional(&format!("{prefix}_QSLRDATE"), parse_date),
+            sent_date: reader.optional(&format!("{prefix}_QSLSDATE"), parse_date),
+        }
+    }
+
+    fn write_to(&self, record: &mut Record, prefix: &str) {
+        set_optional_field(record, &format!("{prefix}_QSL_RCVD"), self.receive);
+        set_optional_field(record, &format!("{prefix}_QSL_SENT"), self.send);
+        set_optional_field(
+            record,
+            &format!("{prefix}_QSLRDATE"),
+            self.received_date.map(format_date),
+        );
+        set_optional_field(
+            record,
+            &format!("{prefix}_QSLSDATE"),
+            self.sent_date.map(format_date),
+        );
+    }
+}
+
+/// Upload status for logbook services (Club Log, QRZ.com).
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct ServiceUploadStatus {
+    pub status: Option<QsoUploadStatus>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub date: Option<Date>,
+}
+
+impl ServiceUploadStatus {
+    fn read(reader: &mut FieldReader, prefix: &str) -> ServiceUploadStatus {
+        ServiceUploadStatus {
+            status: reader.optional(&format!("{prefix}_QSO_UPLOAD_STATUS"), parse_field),
+            date: reader.optional(&format!("{prefix}_QSO_UPLOAD_DATE"), parse_date),
+        }
+    }
+
+    fn write_to(&self, record: &mut Record, prefix: &str) {
+        set_optional_field(record, &format!("{prefix}_QSO_UPLOAD_STATUS"), self.status);
+        set_optional_field(
+            record,
+            &format!("{prefix}_QSO_UPLOAD_DATE"),
+            self.date.map(format_date),
+        );
+    }
+}
+
+/// QSL and confirmation status of a QSO.
+///
+/// Unknown enumeration letters and malformed dates are treated as absent
+/// so that a single broken field does not reject the whole record.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct QslStatus {
     pub receive: Option<QslReceiveStatus>,
     pub receive_via: Option<QslVia>,
     pub send: Option<QslSendStatus>,
     pub send_via: Option<QslVia>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub received_date: Option<Date>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub sent_date: Option<Date>,
+
+    /// Route of QSL card (`QSL_VIA`), typically a QSL manager.
+    pub route: Option<CompactString>,
+    pub message: Option<CompactString>,
+    pub message_received: Option<CompactString>,
+
+    pub lotw: ServiceQslStatus,
+    pub eqsl: ServiceQslStatus,
+    pub clublog: ServiceUploadStatus,
+    pub qrzcom: ServiceUploadStatus,
 }
 
 impl QslStatus {
-    pub fn new(record: &Record) -> Result<QslStatus, QsoError> {
-        let receive = record
-            .field("QSL_RCVD")
-            .map(FromStr::from_str)
-            .transpose()?;
-        let send = record
-            .field("QSL_SENT")
-            .map(FromStr::from_str)
-            .transpose()?;
-        let receive_via = record
-            .field("QSL_RCVD_VIA")
-            .map(FromStr::from_str)
-            .transpose()?;
-        let send_via = record
-            .field("QSL_SENT_VIA")
-            .map(FromStr::from_str)
-            .transpose()?;
-        Ok(QslStatus {
-            receive,
-            receive_via,
-            send,
-            send_via,
-        })
+    pub fn new(record: &Record) -> QslStatus {
+        QslStatus::read(&mut FieldReader::new(record))
+    }
+
+    pub(crate) fn read(reader: &mut FieldReader) -> QslStatus {
+        let route = reader.text(&["QSL_VIA"]);
+        let message = reader.text(&["QSLMSG_INTL", "QSLMSG"]);
+        let message_received = reader.text(&["QSLMSG_RCVD"]);
+
+        QslStatus {
+            receive: reader.optional("QSL_RCVD", parse_field),
+            receive_via: reader.optional("QSL_RCVD_VIA", parse_field),
+            send: reader.optional("QSL_SENT", parse_field),
+            send_via: reader.optional("QSL_SENT_VIA", parse_field),
+            received_date: reader.optional("QSLRDATE", parse_date),
+            sent_date: reader.optional("QSLSDATE", parse_date),
+            route: route.map(|s| s.to_compact_string()),
+            message: message.map(|s| s.to_compact_string()),
+            message_received: message_received.map(|s| s.to_compact_string()),
+            lotw: ServiceQslStatus::read(reader, "LOTW"),
+            eqsl: ServiceQslStatus::read(reader, "EQSL"),
+            clublog: ServiceUploadStatus::read(reader, "CLUBLOG"),
+            qrzcom: ServiceUploadStatus::read(reader, "QRZCOM"),
+        }
+    }
+
+    /// Writes back into ADIF fields.
+    pub fn write_to(&self, record: &mut Record) {
+        set_optional_field(record, "QSL_RCVD", self.receive);
+        set_optional_field(record, "QSL_RCVD_VIA", self.receive_via);
+        set_optional_field(record, "QSL_SENT", self.send);
+        set_optional_field(record, "QSL_SENT_VIA", self.send_via);
+        set_optional_field(record, "QSLRDATE", self.received_date.map(format_date));
+        set_optional_field(record, "QSLSDATE", self.sent_date.map(format_date));
+        set_optional_field(record, "QSL_VIA", self.route.as_ref());
+        set_optional_intl_field(record, "QSLMSG", "QSLMSG_INTL", self.message.as_deref());
+        set_optional_field(record, "QSLMSG_RCVD", self.message_received.as_ref());
+        self.lotw.write_to(record, "LOTW");
+        self.eqsl.write_to(record, "EQSL");
+        self.clublog.write_to(record, "CLUBLOG");
+        self.qrzcom.write_to(record, "QRZCOM");
+    }
+
+    /// Returns services which confirmed this QSO.
+    pub fn confirmed_by(&self) -> impl Iterator<Item = QslService> {
+        [
+            (QslService::Paper, self.receive),
+            (QslService::Lotw, self.lotw.receive),
+            (QslService::Eqsl, self.eqsl.receive),
+        ]
+        .into_iter()
+        .filter_map(|(service, status)| status?.is_confirmed().then_some(service))
+    }
+
+    /// Checks whether this QSO is confirmed by any service.
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_by().next().is_some()
+    }
+}
+
+fn parse_date(value: &str) -> Result<Date, QsoError> {
+    Ok(Date::parse(value, ADIF_DATE)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+
+    use super::{QslReceiveStatus, QslService, QslStatus, QsoUploadStatus};
+
+    #[test]
+    fn parses_service_status() {
+        let adif = read_adi(
+            "<QSL_RCVD:1>N<LOTW_QSL_RCVD:1>Y<LOTW_QSLRDATE:8>20250102<EQSL_QSL_RCVD:1>v<CLUBLOG_QSO_UPLOAD_STATUS:1>M<QSL_VIA:6>JA1XXX<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        let status = QslStatus::new(&adif.records()[0]);
+
+        assert_eq!(status.receive, Some(QslReceiveStatus::Unconfirmed));
+        assert_eq!(status.lotw.receive, Some(QslReceiveStatus::Confirmed));
+        assert_eq!(
+            status.lotw.received_date.map(|d| d.to_string()).as_deref(),
+            Some("2025-01-02")
+        );
+        assert_eq!(status.clublog.status, Some(QsoUploadStatus::Modified));
+        assert_eq!(status.route.as_deref(), Some("JA1XXX"));
+        assert_eq!(
+            status.confirmed_by().collect::<Vec<_>>(),
+            vec![QslService::Lotw, QslService::Eqsl]
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_letters() {
+        let adif =
+            read_adi("<QSL_RCVD:1>X<QSL_SENT:1>Y<eor>", LengthMode::Bytes).expect("valid ADI");
+        let status = QslStatus::new(&adif.records()[0]);
+
+        assert_eq!(status.receive, None);
+        assert!(status.send.is_some());
+        assert!(!status.is_confirmed());
     }
 }
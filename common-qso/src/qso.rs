@@ -0,0 +1,330 @@
+use std::collections::BTreeMap;
+
+use adif_reader::document::Record;
+use callfind::{grid_locator::GridLocator, location::Location};
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+use time::UtcOffset;
+
+use crate::{
+    diagnostic::{FieldDiagnostic, FieldReader, Severity},
+    exchange::QsoExchanges,
+    parse_field,
+    propagation::SatelliteInfo,
+    qsl::QslStatus,
+    record::QsoRecord,
+    reference::ActivityReferences,
+    report::Report,
+    set_optional_field,
+    station::{ContactedStation, read_location, write_location},
+};
+
+/// Determines which diagnostics reject a QSO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Strictness {
+    /// Rejects on any warning or error.
+    Strict,
+
+    /// Rejects only when required fields are missing or malformed.
+    /// Malformed optional fields are ignored.
+    #[default]
+    Lenient,
+}
+
+/// All parsed parts of an ADIF record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Qso {
+    pub record: QsoRecord,
+    pub exchanges: QsoExchanges,
+    pub station: ContactedStation,
+    pub qsl: QslStatus,
+
+    /// Present for satellite QSOs.
+    pub satellite: Option<SatelliteInfo>,
+
+    /// `MY_POTA_REF`, `MY_SOTA_REF`, `MY_WWFF_REF` and `MY_IOTA`.
+    pub my_references: ActivityReferences,
+
+    /// `MY_GRIDSQUARE`.
+    pub my_grid: Option<GridLocator>,
+
+    /// `MY_LAT` and `MY_LON`.
+    pub my_location: Option<Location>,
+
+    /// Offset which `QSO_DATE` and `TIME_ON` were read in, and are written back in.
+    #[serde(skip, default = "utc_offset")]
+    pub offset: UtcOffset,
+
+    /// Diagnostics which did not reject this QSO.
+    #[serde(skip)]
+    pub diagnostics: Vec<FieldDiagnostic>,
+
+    /// Raw text of fields which the parts could not model, such as malformed optional fields.
+    /// Each is written back as is unless the parts set the field; remove it to drop the field.
+    #[serde(skip)]
+    pub preserved: BTreeMap<CompactString, CompactString>,
+}
+
+impl Qso {
+    /// Parses all parts of the record.
+    /// Returns all diagnostics found when the QSO is rejected.
+    pub fn new(
+        record: &Record,
+        offset: UtcOffset,
+        strictness: Strictness,
+    ) -> Result<Qso, Vec<FieldDiagnostic>> {
+        let mut reader = FieldReader::new(record);
+        let qso_record = QsoRecord::read(&mut reader, offset);
+        let exchanges = QsoExchanges::new(record);
+        let station = ContactedStation::read(&mut reader);
+        let qsl = QslStatus::read(&mut reader);
+        let my_references = ActivityReferences::read(&mut reader, "MY_");
+        let my_grid = reader.optional("MY_GRIDSQUARE", parse_field);
+        let my_location = read_location(&mut reader, "MY_", my_grid);
+        let satellite = qso_record
+            .as_ref()
+            .and_then(|r| SatelliteInfo::read(&mut reader, r));
+        if let Some(qso_record) = &qso_record {
+            let category = qso_record.mode_category();
+            for field in ["RST_SENT", "RST_RCVD"] {
+                reader.optional(field, |s| Ok(Report::parse_for(s, category)?));
+            }
+        }
+
+        let (diagnostics, preserved) = reader.into_parts();
+        let rejected = match strictness {
+            Strictness::Strict => diagnostics.iter().any(|d| d.severity >= Severity::Warning),
+            Strictness::Lenient => diagnostics.iter().any(|d| d.severity == Severity::Error),
+        };
+        match qso_record {
+            Some(record) if !rejected => Ok(Qso {
+                record,
+                exchanges,
+                station,
+                qsl,
+                satellite,
+                my_references,
+                my_grid,
+                my_location,
+                offset,
+                diagnostics,
+                preserved,
+            }),
+            _ => Err(diagnostics),
+        }
+    }
+
+    /// Writes all parts back into ADIF fields.
+    /// Fields not modeled by the parts are left untouched,
+    /// and fields in `preserved` are restored if the parts removed them.
+    pub fn write_to(&self, record: &mut Record) {
+        self.record.write_to_in_offset(record, self.offset);
+        self.exchanges.write_to(record);
+        self.station.write_to(record);
+        self.qsl.write_to(record);
+        self.my_references.write_to(record, "MY_");
+        set_optional_field(record, "MY_GRIDSQUARE", self.my_grid);
+        write_location(record, "MY_", self.my_location);
+        match &self.satellite {
+            Some(satellite) => satellite.write_to(record),
+            None => {
+                record.remove_field("SAT_NAME");
+                record.remove_field("SAT_MODE");
+            }
+        }
+
+        for (field, value) in &self.preserved {
+            if record.field(field.as_str()).is_none() {
+                record.set_field(field.as_str(), value.as_str());
+            }
+        }
+    }
+}
+
+fn utc_offset() -> UtcOffset {
+    UtcOffset::UTC
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+    use time::UtcOffset;
+
+    use super::{Qso, Strictness};
+    use crate::{band::Band, diagnostic::Severity, error::QsoError, qsl::QslSendStatus};
+
+    const ADI: &str = "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<RST_SENT:2>59<QSL_RCVD:1>X<eor>";
+
+    #[test]
+    fn collects_diagnostics() {
+        let adif = read_adi(ADI, LengthMode::Bytes).expect("valid ADI");
+        let qso = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Lenient)
+            .expect("must be accepted");
+
+        let fields: Vec<_> = qso.diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["QSL_RCVD", "RST_SENT"]);
+        assert!(
+            qso.diagnostics
+                .iter()
+                .all(|d| d.severity == Severity::Warning)
+        );
+        assert_eq!(qso.diagnostics[0].value.as_deref(), Some("X"));
+    }
+
+    #[test]
+    fn warns_on_malformed_frequency() {
+        let adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:2>2m<MODE:2>FM<CALL:6>JA1XXX<FREQ:7>145.850<FREQ_RX:5>436.x<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        let qso = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Lenient)
+            .expect("must be accepted");
+        assert_eq!(qso.record.frequency_rx, None);
+        let fields: Vec<_> = qso.diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["FREQ_RX"]);
+
+        let adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:2>2m<MODE:2>FM<CALL:6>JA1XXX<FREQ:3>145<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        assert!(Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Lenient).is_ok());
+    }
+
+    #[test]
+    fn rejects_by_strictness() {
+        let adif = read_adi(ADI, LengthMode::Bytes).expect("valid ADI");
+        let diagnostics = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Strict)
+            .expect_err("must be rejected");
+        assert_eq!(diagnostics.len(), 2);
+
+        let adif =
+            read_adi("<CALL:6>JA1XXX<BAND:3>41m<eor>", LengthMode::Bytes).expect("valid ADI");
+        let diagnostics = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Lenient)
+            .expect_err("must be rejected");
+        let fields: Vec<_> = diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["QSO_DATE", "TIME_ON", "BAND", "MODE", "FREQ"]);
+    }
+
+    #[test]
+    fn checks_location_consistency() {
+        let adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<GRIDSQUARE:4>PM95<LAT:11>N035 41.022<LON:11>E139 46.020<MY_GRIDSQUARE:4>PM96<MY_LAT:11>N035 41.022<MY_LON:11>E139 46.020<eor>",
+            LengthMode::Bytes,
+        )
+        .expect("valid ADI");
+        let qso = Qso::new(&adif.records()[0], UtcOffset::UTC, Strictness::Lenient)
+            .expect("must be accepted");
+
+        assert_eq!(
+            qso.station.location.map(|l| l.to_string()).as_deref(),
+            Some("N035 41.022 E139 46.020")
+        );
+        assert_eq!(qso.my_location, qso.station.location);
+        let fields: Vec<_> = qso.diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["MY_GRIDSQUARE"]);
+        assert_eq!(qso.diagnostics[0].value.as_deref(), Some("PM96"));
+    }
+
+    #[test]
+    fn keeps_malformed_fields() {
+        let text = "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<QSL_RCVD:1>X<GRIDSQUARE:4>ZZ99<CQZ:2>41<POTA_REF:3>bad<eor>";
+        let mut adif = read_adi(text, LengthMode::Bytes).expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let qso = Qso::new(record, UtcOffset::UTC, Strictness::Lenient).expect("must be accepted");
+        assert_eq!(qso.diagnostics.len(), 4);
+        qso.write_to(record);
+
+        assert_eq!(record.field("QSL_RCVD"), Some("X"));
+        assert_eq!(record.field("GRIDSQUARE"), Some("ZZ99"));
+        assert_eq!(record.field("CQZ"), Some("41"));
+        assert_eq!(record.field("POTA_REF"), Some("bad"));
+    }
+
+    #[test]
+    fn drops_cleared_fields() {
+        let text = "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<CQZ:2>41<MY_GRIDSQUARE:4>PM96<MY_LAT:11>N035 41.022<MY_LON:11>E139 46.020<eor>";
+        let mut adif = read_adi(text, LengthMode::Bytes).expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let mut qso =
+            Qso::new(record, UtcOffset::UTC, Strictness::Lenient).expect("must be accepted");
+        assert_eq!(qso.diagnostics.len(), 2);
+
+        qso.my_grid = None;
+        qso.preserved.remove("CQZ");
+        qso.write_to(record);
+        assert_eq!(record.field("MY_GRIDSQUARE"), None);
+        assert_eq!(record.field("CQZ"), None);
+
+        qso.station.cq_zone = Some(25);
+        qso.write_to(record);
+        assert_eq!(record.field("CQZ"), Some("25"));
+    }
+
+    #[test]
+    fn keeps_unpaired_location() {
+        let text = "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<LAT:11>N035 40.000<MY_LAT:11>N035 40.000<MY_LON:4>east<eor>";
+        let mut adif = read_adi(text, LengthMode::Bytes).expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let qso = Qso::new(record, UtcOffset::UTC, Strictness::Lenient).expect("must be accepted");
+        assert_eq!((qso.station.location, qso.my_location), (None, None));
+        assert!(qso.diagnostics.iter().any(|d| d.field == "LAT"
+            && d.severity == Severity::Info
+            && matches!(d.reason, QsoError::IncompleteLocation)));
+        qso.write_to(record);
+
+        assert_eq!(record.field("LAT"), Some("N035 40.000"));
+        assert_eq!(record.field("LON"), None);
+        assert_eq!(record.field("MY_LAT"), Some("N035 40.000"));
+        assert_eq!(record.field("MY_LON"), Some("east"));
+
+        let text = "<QSO_DATE:8>20250102<TIME_ON:6>123456<BAND:3>40m<MODE:2>CW<CALL:6>JA1XXX<FREQ:5>7.010<LAT:11>N035 40.000<eor>";
+        let mut adif = read_adi(text, LengthMode::Bytes).expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let mut qso = Qso::new(record, UtcOffset::UTC, Strictness::Strict)
+            .expect("a lone coordinate does not reject");
+        qso.preserved.remove("LAT");
+        qso.write_to(record);
+        assert_eq!(record.field("LAT"), None);
+    }
+
+    #[test]
+    fn writes_back_in_read_offset() {
+        let mut adif = read_adi(ADI, LengthMode::Bytes).expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let jst = UtcOffset::from_hms(9, 0, 0).expect("valid offset");
+        let qso = Qso::new(record, jst, Strictness::Lenient).expect("must be accepted");
+        assert_eq!(qso.record.datetime.hour(), 3);
+        qso.write_to(record);
+
+        assert_eq!(record.field("QSO_DATE"), Some("20250102"));
+        assert_eq!(record.field("TIME_ON"), Some("123456"));
+    }
+
+    #[test]
+    fn writes_back() {
+        let mut adif = read_adi(
+            "<QSO_DATE:8>20250102<TIME_ON:6>123400<BAND:3>40M<MODE:3>SSB<CALL:6>JA1XXX<FREQ:5>7.100<SRX_STRING:3>13M<NAME_INTL:2>太郎<MY_RIG:3>FT8<eor>",
+            LengthMode::Codepoints,
+        )
+        .expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let mut qso =
+            Qso::new(record, UtcOffset::UTC, Strictness::Lenient).expect("must be accepted");
+        qso.record.band = Band::Meter20;
+        qso.qsl.send = Some(QslSendStatus::Sent);
+        qso.qsl.sent_date = qso.record.datetime.date().next_day();
+        qso.write_to(record);
+
+        assert_eq!(record.field("BAND"), Some("20m"));
+        assert_eq!(record.field("TIME_ON"), Some("123400"));
+        assert_eq!(record.field("QSL_SENT"), Some("Y"));
+        assert_eq!(record.field("QSLSDATE"), Some("20250103"));
+        assert_eq!(record.field("SRX_STRING"), Some("13M"));
+        assert_eq!(record.field("SRX"), None);
+        assert_eq!(record.field("NAME_INTL"), Some("太郎"));
+        assert_eq!(record.field("NAME"), None);
+        assert_eq!(record.field("MY_RIG"), Some("FT8"));
+    }
+}
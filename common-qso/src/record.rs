@@ -1,17 +1,21 @@
 use adif_reader::document::Record;
 use compact_str::{CompactString, ToCompactString};
-use time::{
-    Date, OffsetDateTime, Time, UtcDateTime, UtcOffset, format_description::BorrowedFormatItem,
-    macros::format_description,
-};
-
-use crate::{band::Band, error::QsoError, get_required_field};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use time::{Date, OffsetDateTime, Time, UtcDateTime, UtcOffset};
 
-const ADIF_DATE: &[BorrowedFormatItem<'_>] = format_description!(
-    "[year repr:full padding:zero][month repr:numerical padding:zero][day padding:zero]"
-);
-const ADIF_TIME: &[BorrowedFormatItem<'_>] =
-    format_description!("[hour repr:24 padding:zero][minute padding:zero][second padding:zero]");
+use crate::{
+    ADIF_DATE, ADIF_TIME,
+    band::{Band, parse_frequency},
+    diagnostic::FieldReader,
+    error::QsoError,
+    format_date,
+    mode::ModeCategory,
+    parse_field,
+    propagation::{Propagation, SatelliteLink},
+    serde_format::Rfc3339Utc,
+    set_optional_field,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QsoExchange {
@@ -19,35 +23,120 @@ pub struct QsoExchange {
     pub number: CompactString,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QsoRecord {
+    #[serde_as(as = "Rfc3339Utc")]
     pub datetime: UtcDateTime,
     pub band: Band,
     pub mode: CompactString,
+    pub submode: Option<CompactString>,
     pub call: CompactString,
     pub frequency: CompactString,
+
+    /// `BAND_RX`, set for cross-band and satellite QSOs.
+    pub band_rx: Option<Band>,
+
+    /// `FREQ_RX` in MHz.
+    pub frequency_rx: Option<CompactString>,
+
+    /// `PROP_MODE`.
+    pub propagation: Option<Propagation>,
+
+    /// `TX_PWR` in watts.
+    pub tx_power: Option<CompactString>,
 }
 
 impl QsoRecord {
     pub fn new(record: &Record, offset: UtcOffset) -> Result<QsoRecord, QsoError> {
-        let datetime = OffsetDateTime::new_in_offset(
-            Date::parse(get_required_field(record, "QSO_DATE")?, ADIF_DATE)?,
-            Time::parse(get_required_field(record, "TIME_ON")?, ADIF_TIME)?,
-            offset,
-        )
-        .to_utc();
-
-        let adif_band = get_required_field(record, "BAND")?;
-        let mode = get_required_field(record, "MODE")?;
-        let callsign = get_required_field(record, "CALL")?;
-        let frequency = get_required_field(record, "FREQ")?;
-
-        Ok(QsoRecord {
-            datetime,
-            band: adif_band.parse()?,
-            mode: mode.to_compact_string(),
-            call: callsign.to_compact_string(),
-            frequency: frequency.to_compact_string(),
+        let mut reader = FieldReader::new(record);
+        let qso_record = QsoRecord::read(&mut reader, offset);
+        reader.finish(qso_record)
+    }
+
+    pub(crate) fn read(reader: &mut FieldReader, offset: UtcOffset) -> Option<QsoRecord> {
+        let date = reader.required("QSO_DATE", |s| Ok(Date::parse(s, ADIF_DATE)?));
+        let time = reader.required("TIME_ON", |s| Ok(Time::parse(s, ADIF_TIME)?));
+        let band = reader.required("BAND", parse_field);
+        let mode = reader.required_text("MODE");
+        let submode = reader.text(&["SUBMODE"]);
+        let callsign = reader.required_text("CALL");
+        let frequency = reader.required("FREQ", decimal_text);
+        let band_rx = reader.optional("BAND_RX", parse_field);
+        let frequency_rx = reader.optional("FREQ_RX", decimal_text);
+        let propagation = reader.optional("PROP_MODE", parse_field);
+        let tx_power = reader.optional("TX_PWR", decimal_text);
+
+        Some(QsoRecord {
+            datetime: OffsetDateTime::new_in_offset(date?, time?, offset).to_utc(),
+            band: band?,
+            mode: mode?.to_compact_string(),
+            submode: submode.map(|s| s.to_compact_string()),
+            call: callsign?.to_compact_string(),
+            frequency: frequency?,
+            band_rx,
+            frequency_rx,
+            propagation,
+            tx_power,
+        })
+    }
+
+    /// Writes back into ADIF fields. Date and time are written in UTC, as ADIF specifies.
+    pub fn write_to(&self, record: &mut Record) {
+        self.write_to_in_offset(record, UtcOffset::UTC);
+    }
+
+    /// Writes back into ADIF fields, with date and time in `offset`.
+    /// Use the offset passed to `new` to keep non-UTC records as they were.
+    pub fn write_to_in_offset(&self, record: &mut Record, offset: UtcOffset) {
+        let datetime = self.datetime.to_offset(offset);
+        record.set_field("QSO_DATE", format_date(datetime.date()));
+        record.set_field(
+            "TIME_ON",
+            datetime
+                .time()
+                .format(ADIF_TIME)
+                .expect("time must be formattable"),
+        );
+        record.set_field("BAND", self.band.to_string());
+        record.set_field("MODE", self.mode.as_str());
+        set_optional_field(record, "SUBMODE", self.submode.as_ref());
+        record.set_field("CALL", self.call.as_str());
+        record.set_field("FREQ", self.frequency.as_str());
+        set_optional_field(record, "BAND_RX", self.band_rx);
+        set_optional_field(record, "FREQ_RX", self.frequency_rx.as_ref());
+        set_optional_field(record, "PROP_MODE", self.propagation);
+        set_optional_field(record, "TX_PWR", self.tx_power.as_ref());
+    }
+
+    pub fn mode_category(&self) -> ModeCategory {
+        ModeCategory::from_adif(&self.mode, self.submode.as_deref())
+    }
+
+    /// Transmitting side from `BAND` and `FREQ`, the uplink of satellite QSOs.
+    pub fn uplink(&self) -> SatelliteLink {
+        SatelliteLink {
+            band: self.band,
+            frequency: Some(self.frequency.clone()),
+        }
+    }
+
+    /// Receiving side from `BAND_RX` and `FREQ_RX`, the downlink of satellite QSOs.
+    /// The band is derived from `FREQ_RX` if `BAND_RX` is absent.
+    pub fn downlink(&self) -> Option<SatelliteLink> {
+        let band = match self.band_rx {
+            Some(band) => band,
+            None => Band::from_frequency(parse_frequency(self.frequency_rx.as_deref()?)?)?,
+        };
+        Some(SatelliteLink {
+            band,
+            frequency: self.frequency_rx.clone(),
         })
     }
 }
+
+/// Validates a decimal number field, keeping its text as is.
+fn decimal_text(value: &str) -> Result<CompactString, QsoError> {
+    value.parse::<f64>()?;
+    Ok(value.to_compact_string())
+}
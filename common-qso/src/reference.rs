@@ -0,0 +1,295 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use adif_reader::document::Record;
+use compact_str::{CompactString, ToCompactString};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+use crate::{diagnostic::FieldReader, parse_field, set_optional_field, station::Continent};
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+#[error("invalid {kind} reference: {value}")]
+pub struct InvalidReference {
+    pub kind: &'static str,
+    pub value: CompactString,
+}
+
+/// POTA park reference such as `JA-0001` or `K-0059@US-CA`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct PotaRef {
+    /// Program prefix, such as `JA` or `K`.
+    pub program: CompactString,
+    pub number: u32,
+
+    /// ISO 3166-2 subdivision given after `@`, such as `US-CA`.
+    pub location: Option<CompactString>,
+}
+
+/// SOTA summit reference such as `JA/TK-001`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct SotaRef {
+    pub association: CompactString,
+    pub region: CompactString,
+    pub number: u16,
+}
+
+/// WWFF reference such as `JAFF-0001`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct WwffRef {
+    /// Program prefix including `FF`, such as `JAFF`.
+    pub program: CompactString,
+    pub number: u16,
+}
+
+/// IOTA island group reference such as `AS-007`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct IotaRef {
+    pub continent: Continent,
+    pub number: u16,
+}
+
+impl PotaRef {
+    /// Parses comma-separated references of a "n-fer" activation.
+    pub fn parse_list(s: &str) -> Result<Vec<PotaRef>, InvalidReference> {
+        s.split(',').map(|r| r.trim().parse()).collect()
+    }
+}
+
+/// Splits `PREFIX-DIGITS` where prefix is 1 to 4 alphanumerics
+/// and digits has one of `digit_lengths` characters.
+fn split_numbered<'a, T: FromStr>(s: &'a str, digit_lengths: &[usize]) -> Option<(&'a str, T)> {
+    let (prefix, digits) = s.split_once('-')?;
+    let prefix_valid =
+        (1..=4).contains(&prefix.len()) && prefix.bytes().all(|c| c.is_ascii_alphanumeric());
+    let digits_valid =
+        digit_lengths.contains(&digits.len()) && digits.bytes().all(|c| c.is_ascii_digit());
+    if !prefix_valid || !digits_valid {
+        return None;
+    }
+    Some((prefix, digits.parse().ok()?))
+}
+
+impl FromStr for PotaRef {
+    type Err = InvalidReference;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidReference {
+            kind: "POTA",
+            value: s.to_compact_string(),
+        };
+        let upper = s.to_ascii_uppercase();
+        let (park, location) = match upper.split_once('@') {
+            Some((park, location)) => (park, Some(location)),
+            None => (upper.as_str(), None),
+        };
+        let (program, number) = split_numbered(park, &[4, 5]).ok_or_else(invalid)?;
+        if let Some(location) = location {
+            let (country, subdivision) = location.split_once('-').ok_or_else(invalid)?;
+            let country_valid =
+                country.len() == 2 && country.bytes().all(|c| c.is_ascii_uppercase());
+            let subdivision_valid = (1..=3).contains(&subdivision.len())
+                && subdivision.bytes().all(|c| c.is_ascii_alphanumeric());
+            if !country_valid || !subdivision_valid {
+                return Err(invalid());
+            }
+        }
+
+        Ok(PotaRef {
+            program: program.to_compact_string(),
+            number,
+            location: location.map(|l| l.to_compact_string()),
+        })
+    }
+}
+
+impl FromStr for SotaRef {
+    type Err = InvalidReference;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidReference {
+            kind: "SOTA",
+            value: s.to_compact_string(),
+        };
+        let upper = s.to_ascii_uppercase();
+        let (association, summit) = upper.split_once('/').ok_or_else(invalid)?;
+        let (region, number) = split_numbered(summit, &[3]).ok_or_else(invalid)?;
+        let association_valid = (1..=4).contains(&association.len())
+            && association.bytes().all(|c| c.is_ascii_alphanumeric());
+        if !association_valid || region.len() != 2 {
+            return Err(invalid());
+        }
+
+        Ok(SotaRef {
+            association: association.to_compact_string(),
+            region: region.to_compact_string(),
+            number,
+        })
+    }
+}
+
+impl FromStr for WwffRef {
+    type Err = InvalidReference;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidReference {
+            kind: "WWFF",
+            value: s.to_compact_string(),
+        };
+        let upper = s.to_ascii_uppercase();
+        let (program, number) = split_numbered(&upper, &[4]).ok_or_else(invalid)?;
+        if program.len() < 3 || !program.ends_with("FF") {
+            return Err(invalid());
+        }
+
+        Ok(WwffRef {
+            program: program.to_compact_string(),
+            number,
+        })
+    }
+}
+
+impl FromStr for IotaRef {
+    type Err = InvalidReference;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidReference {
+            kind: "IOTA",
+            value: s.to_compact_string(),
+        };
+        let (continent, number) = split_numbered::<u16>(s, &[3]).ok_or_else(invalid)?;
+        if number == 0 {
+            return Err(invalid());
+        }
+
+        Ok(IotaRef {
+            continent: continent.parse().map_err(|_| invalid())?,
+            number,
+        })
+    }
+}
+
+impl Display for PotaRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}-{:04}", self.program, self.number)?;
+        if let Some(location) = &self.location {
+            write!(f, "@{location}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SotaRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}/{}-{:03}", self.association, self.region, self.number)
+    }
+}
+
+impl Display for WwffRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}-{:04}", self.program, self.number)
+    }
+}
+
+impl Display for IotaRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}-{:03}", self.continent, self.number)
+    }
+}
+
+/// Portable activity references of either station.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActivityReferences {
+    pub pota: Vec<PotaRef>,
+    pub sota: Option<SotaRef>,
+    pub wwff: Option<WwffRef>,
+    pub iota: Option<IotaRef>,
+}
+
+impl ActivityReferences {
+    /// Reads `{prefix}POTA_REF` and so on; `prefix` is empty or `MY_`.
+    pub(crate) fn read(reader: &mut FieldReader, prefix: &str) -> ActivityReferences {
+        let pota = reader.optional(&format!("{prefix}POTA_REF"), |s| {
+            Ok(PotaRef::parse_list(s)?)
+        });
+        ActivityReferences {
+            pota: pota.unwrap_or_default(),
+            sota: reader.optional(&format!("{prefix}SOTA_REF"), parse_field),
+            wwff: reader.optional(&format!("{prefix}WWFF_REF"), parse_field),
+            iota: reader.optional(&format!("{prefix}IOTA"), parse_field),
+        }
+    }
+
+    pub(crate) fn write_to(&self, record: &mut Record, prefix: &str) {
+        let pota = self.pota.iter().map(|r| r.to_string());
+        set_optional_field(
+            record,
+            &format!("{prefix}POTA_REF"),
+            (!self.pota.is_empty()).then(|| pota.collect::<Vec<_>>().join(",")),
+        );
+        set_optional_field(record, &format!("{prefix}SOTA_REF"), self.sota.as_ref());
+        set_optional_field(record, &format!("{prefix}WWFF_REF"), self.wwff.as_ref());
+        set_optional_field(record, &format!("{prefix}IOTA"), self.iota);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pota.is_empty() && self.sota.is_none() && self.wwff.is_none() && self.iota.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IotaRef, PotaRef, SotaRef, WwffRef};
+    use crate::station::Continent;
+
+    #[test]
+    fn parses_references() {
+        let pota: PotaRef = "ja-0001".parse().expect("valid POTA");
+        assert_eq!(pota.to_string(), "JA-0001");
+        let pota: PotaRef = "K-10059@US-CA".parse().expect("valid POTA");
+        assert_eq!(pota.number, 10059);
+        assert_eq!(pota.location.as_deref(), Some("US-CA"));
+
+        let sota: SotaRef = "JA/TK-001".parse().expect("valid SOTA");
+        assert_eq!(sota.association, "JA");
+        assert_eq!(sota.region, "TK");
+        assert_eq!(sota.number, 1);
+
+        let wwff: WwffRef = "JAFF-0001".parse().expect("valid WWFF");
+        assert_eq!(wwff.to_string(), "JAFF-0001");
+
+        let iota: IotaRef = "AS-007".parse().expect("valid IOTA");
+        assert_eq!(iota.continent, Continent::Asia);
+        assert_eq!(iota.to_string(), "AS-007");
+    }
+
+    #[test]
+    fn rejects_malformed_references() {
+        assert!("JA-01".parse::<PotaRef>().is_err());
+        assert!("JA-0001@JAPAN".parse::<PotaRef>().is_err());
+        assert!("JA/TOK-001".parse::<SotaRef>().is_err());
+        assert!("JA-TK-001".parse::<SotaRef>().is_err());
+        assert!("JA-0001".parse::<WwffRef>().is_err());
+        assert!("XX-007".parse::<IotaRef>().is_err());
+        assert!("AS-000".parse::<IotaRef>().is_err());
+    }
+
+    #[test]
+    fn parses_pota_n_fer() {
+        let refs = PotaRef::parse_list("JA-0001, JA-1234").expect("valid list");
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[1].number, 1234);
+        assert!(PotaRef::parse_list("JA-0001,,JA-1234").is_err());
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use compact_str::{CompactString, ToCompactString};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error as ThisError;
+
+use crate::mode::ModeCategory;
+
+/// Suffix appended to RST report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RstSuffix {
+    /// `X`: crystal-controlled stable signal.
+    Crystal,
+
+    /// `C`: chirp.
+    Chirp,
+
+    /// `K`: key clicks.
+    KeyClick,
+
+    /// `A`: aurora.
+    Aurora,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum Report {
+    /// Readability and strength, used for phone.
+    Rs { readability: u8, strength: u8 },
+
+    /// Readability, strength and tone, used for CW and keyboard data modes.
+    Rst {
+        readability: u8,
+        strength: u8,
+        tone: u8,
+        suffix: Option<RstSuffix>,
+    },
+
+    /// Signal-to-noise ratio in dB, used for weak signal modes.
+    /// `roger` is set for `R-05` form.
+    Decibel { value: i8, roger: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum ReportError {
+    #[error("invalid report: {0}")]
+    Invalid(CompactString),
+
+    #[error("report {report} is not suitable for {category} mode")]
+    Mismatch {
+        report: Report,
+        category: ModeCategory,
+    },
+}
+
+impl Report {
+    /// Parses report for the mode category.
+    /// Unsigned numbers are treated as dB for weak signal modes.
+    pub fn parse_for(s: &str, category: ModeCategory) -> Result<Report, ReportError> {
+        let report = match category {
+            ModeCategory::WeakSignal => Self::parse_decibel(s.trim())
+                .ok_or_else(|| ReportError::Invalid(s.to_compact_string()))?,
+            _ => s.parse()?,
+        };
+        report.validate(category)?;
+        Ok(report)
+    }
+
+    /// Checks whether the report form matches the mode category.
+    pub fn validate(&self, category: ModeCategory) -> Result<(), ReportError> {
+        let suitable = matches!(
+            (self, category),
+            (Report::Rs { .. }, ModeCategory::Phone | ModeCategory::Image)
+                | (
+                    Report::Rst { .. },
+                    ModeCategory::Cw | ModeCategory::Data | ModeCategory::Image
+                )
+                | (Report::Decibel { .. }, ModeCategory::WeakSignal)
+                | (_, ModeCategory::Other)
+        );
+        if suitable {
+            Ok(())
+        } else {
+            Err(ReportError::Mismatch {
+                report: *self,
+                category,
+            })
+        }
+    }
+
+    pub fn readability(&self) -> Option<u8> {
+        match self {
+            Report::Rs { readability, .. } | Report::Rst { readability, .. } => Some(*readability),
+            Report::Decibel { .. } => None,
+        }
+    }
+
+    pub fn strength(&self) -> Option<u8> {
+        match self {
+            Report::Rs { strength, .. } | Report::Rst { strength, .. } => Some(*strength),
+            Report::Decibel { .. } => None,
+        }
+    }
+
+    pub fn tone(&self) -> Option<u8> {
+        match self {
+            Report::Rst { tone, .. } => Some(*tone),
+            _ => None,
+        }
+    }
+
+    pub fn decibel(&self) -> Option<i8> {
+        match self {
+            Report::Decibel { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn parse_decibel(s: &str) -> Option<Report> {
+        let (roger, rest) = match s.strip_prefix(['R', 'r']) {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let digits = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+        if digits.is_empty() || digits.len() > 2 || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        // WSJT-X reports range from -50 to +49 dB
+        match rest.parse() {
+            Ok(value @ -50..=49) => Some(Report::Decibel { value, roger }),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Report {
+    type Err = ReportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ReportError::Invalid(s.to_compact_string());
+        let trimmed = s.trim();
+        if trimmed.starts_with(['+', '-', 'R', 'r']) {
+            return Self::parse_decibel(trimmed).ok_or_else(invalid);
+        }
+
+        let b = trimmed.as_bytes();
+        let digit =
+            |c: u8, range: RangeInclusive<u8>| c.is_ascii_digit() && range.contains(&(c - b'0'));
+        match b {
+            [r, s] if digit(*r, 1..=5) && digit(*s, 1..=9) => Ok(Report::Rs {
+                readability: r - b'0',
+                strength: s - b'0',
+            }),
+            [r, s, t, suffix @ ..] if digit(*r, 1..=5) && digit(*s, 1..=9) && digit(*t, 1..=9) => {
+                let suffix = match suffix {
+                    [] => None,
+                    [b'X' | b'x'] => Some(RstSuffix::Crystal),
+                    [b'C' | b'c'] => Some(RstSuffix::Chirp),
+                    [b'K' | b'k'] => Some(RstSuffix::KeyClick),
+                    [b'A' | b'a'] => Some(RstSuffix::Aurora),
+                    _ => return Err(invalid()),
+                };
+                Ok(Report::Rst {
+                    readability: r - b'0',
+                    strength: s - b'0',
+                    tone: t - b'0',
+                    suffix,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for RstSuffix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            RstSuffix::Crystal => write!(f, "X"),
+            RstSuffix::Chirp => write!(f, "C"),
+            RstSuffix::KeyClick => write!(f, "K"),
+            RstSuffix::Aurora => write!(f, "A"),
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Report::Rs {
+                readability,
+                strength,
+            } => write!(f, "{readability}{strength}"),
+            Report::Rst {
+                readability,
+                strength,
+                tone,
+                suffix,
+            } => {
+                write!(f, "{readability}{strength}{tone}")?;
+                if let Some(suffix) = suffix {
+                    write!(f, "{suffix}")?;
+                }
+                Ok(())
+            }
+            Report::Decibel { value, roger } => {
+                write!(f, "{}{value:+03}", if *roger { "R" } else { "" })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Report, ReportError, RstSuffix};
+    use crate::mode::ModeCategory;
+
+    #[test]
+    fn parses_reports() {
+        assert_eq!(
+            "59".parse(),
+            Ok(Report::Rs {
+                readability: 5,
+                strength: 9
+            })
+        );
+        assert_eq!(
+            "599K".parse(),
+            Ok(Report::Rst {
+                readability: 5,
+                strength: 9,
+                tone: 9,
+                suffix: Some(RstSuffix::KeyClick)
+            })
+        );
+        assert_eq!(
+            "R-05".parse(),
+            Ok(Report::Decibel {
+                value: -5,
+                roger: true
+            })
+        );
+        assert_eq!(
+            Report::parse_for("12", ModeCategory::WeakSignal),
+            Ok(Report::Decibel {
+                value: 12,
+                roger: false
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_impossible_reports() {
+        assert!("69".parse::<Report>().is_err());
+        assert!("509".parse::<Report>().is_err());
+        assert!("599Z".parse::<Report>().is_err());
+        assert!("-99".parse::<Report>().is_err());
+    }
+
+    #[test]
+    fn validates_mode_category() {
+        assert!(matches!(
+            Report::parse_for("599", ModeCategory::Phone),
+            Err(ReportError::Mismatch { .. })
+        ));
+        assert!(matches!(
+            Report::parse_for("59", ModeCategory::Cw),
+            Err(ReportError::Mismatch { .. })
+        ));
+        assert!(Report::parse_for("-12", ModeCategory::WeakSignal).is_ok());
+    }
+
+    #[test]
+    fn formats_reports() {
+        let report: Report = "R-05".parse().expect("valid report");
+        assert_eq!(report.to_string(), "R-05");
+        let report: Report = "+7".parse().expect("valid report");
+        assert_eq!(report.to_string(), "+07");
+    }
+
+    #[test]
+    fn categorizes_modes() {
+        assert_eq!(
+            ModeCategory::from_adif("MFSK", Some("FT4")),
+            ModeCategory::WeakSignal
+        );
+        assert_eq!(ModeCategory::from_adif("ssb", None), ModeCategory::Phone);
+        assert_eq!(ModeCategory::from_adif("RTTY", None), ModeCategory::Data);
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Deserializer, Serializer, de::Error as _, ser::Error as _};
+use serde_with::{DeserializeAs, SerializeAs};
+use time::{
+    Date, OffsetDateTime, UtcDateTime,
+    format_description::{BorrowedFormatItem, well_known::Rfc3339},
+    macros::format_description,
+};
+
+const ISO_DATE: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+/// Serializes `UtcDateTime` in RFC 3339 (`2025-01-02T12:34:56Z`).
+pub struct Rfc3339Utc;
+
+impl SerializeAs<UtcDateTime> for Rfc3339Utc {
+    fn serialize_as<S: Serializer>(source: &UtcDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let formatted = OffsetDateTime::from(*source)
+            .format(&Rfc3339)
+            .map_err(S::Error::custom)?;
+        serializer.serialize_str(&formatted)
+    }
+}
+
+impl<'de> DeserializeAs<'de, UtcDateTime> for Rfc3339Utc {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<UtcDateTime, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let datetime = OffsetDateTime::parse(&text, &Rfc3339).map_err(D::Error::custom)?;
+        Ok(datetime.to_utc())
+    }
+}
+
+/// Serializes `Date` in ISO 8601 (`2025-01-02`).
+pub struct IsoDate;
+
+impl SerializeAs<Date> for IsoDate {
+    fn serialize_as<S: Serializer>(source: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let formatted = source.format(ISO_DATE).map_err(S::Error::custom)?;
+        serializer.serialize_str(&formatted)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Date> for IsoDate {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Date::parse(&text, ISO_DATE).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,228 @@
+use adif_reader::document::Record;
+use callfind::{
+    grid_locator::{GridLocator, parse_grids},
+    location::{Location, parse_adif_latitude, parse_adif_longitude},
+};
+use compact_str::{CompactString, ToCompactString};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostic::FieldReader, error::QsoError, parse_field, reference::ActivityReferences,
+    set_optional_field, set_optional_intl_field,
+};
+
+pub use callfind::dxcc::{Continent, InvalidContinent};
+
+/// Details of the contacted station.
+/// Each text field prefers its `*_INTL` variant when present.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactedStation {
+    pub name: Option<CompactString>,
+    pub qth: Option<CompactString>,
+    pub grid: Option<GridLocator>,
+    pub vucc_grids: Vec<GridLocator>,
+
+    /// `LAT` and `LON`.
+    pub location: Option<Location>,
+    pub dxcc: Option<u16>,
+    pub country: Option<CompactString>,
+    pub cq_zone: Option<u8>,
+    pub itu_zone: Option<u8>,
+    pub continent: Option<Continent>,
+    pub state: Option<CompactString>,
+    pub county: Option<CompactString>,
+
+    /// `POTA_REF`, `SOTA_REF`, `WWFF_REF` and `IOTA`.
+    pub references: ActivityReferences,
+}
+
+impl ContactedStation {
+    pub fn new(record: &Record) -> Result<ContactedStation, QsoError> {
+        let mut reader = FieldReader::new(record);
+        let station = ContactedStation::read(&mut reader);
+        reader.finish(Some(station))
+    }
+
+    pub(crate) fn read(reader: &mut FieldReader) -> ContactedStation {
+        let name = reader.text(&["NAME_INTL", "NAME"]);
+        let qth = reader.text(&["QTH_INTL", "QTH"]);
+        let country = reader.text(&["COUNTRY_INTL", "COUNTRY"]);
+        let state = reader.text(&["STATE"]);
+        let county = reader.text(&["CNTY"]);
+
+        let vucc_grids = reader.optional("VUCC_GRIDS", |grids| Ok(parse_grids(grids)?));
+        let cq_zone = reader.optional("CQZ", |s| match s.parse()? {
+            z @ 1..=40 => Ok(z),
+            _ => Err(QsoError::OutOfRange("CQZ")),
+        });
+        let itu_zone = reader.optional("ITUZ", |s| match s.parse()? {
+            z @ 1..=90 => Ok(z),
+            _ => Err(QsoError::OutOfRange("ITUZ")),
+        });
+
+        let grid = reader.optional("GRIDSQUARE", parse_field);
+        let location = read_location(reader, "", grid);
+
+        ContactedStation {
+            name: name.map(|s| s.to_compact_string()),
+            qth: qth.map(|s| s.to_compact_string()),
+            grid,
+            vucc_grids: vucc_grids.unwrap_or_default(),
+            location,
+            dxcc: reader.optional("DXCC", parse_field),
+            country: country.map(|s| s.to_compact_string()),
+            cq_zone,
+            itu_zone,
+            continent: reader.optional("CONT", parse_field),
+            state: state.map(|s| s.to_compact_string()),
+            county: county.map(|s| s.to_compact_string()),
+            references: ActivityReferences::read(reader, ""),
+        }
+    }
+
+    /// Writes back into ADIF fields.
+    pub fn write_to(&self, record: &mut Record) {
+        set_optional_intl_field(record, "NAME", "NAME_INTL", self.name.as_deref());
+        set_optional_intl_field(record, "QTH", "QTH_INTL", self.qth.as_deref());
+        set_optional_field(record, "GRIDSQUARE", self.grid);
+        let vucc_grids = self.vucc_grids.iter().map(|g| g.to_string());
+        set_optional_field(
+            record,
+            "VUCC_GRIDS",
+            (!self.vucc_grids.is_empty()).then(|| vucc_grids.collect::<Vec<_>>().join(",")),
+        );
+        write_location(record, "", self.location);
+        set_optional_field(record, "DXCC", self.dxcc);
+        set_optional_intl_field(record, "COUNTRY", "COUNTRY_INTL", self.country.as_deref());
+        set_optional_field(record, "CQZ", self.cq_zone);
+        set_optional_field(record, "ITUZ", self.itu_zone);
+        set_optional_field(record, "CONT", self.continent);
+        set_optional_field(record, "STATE", self.state.as_ref());
+        set_optional_field(record, "CNTY", self.county.as_ref());
+        self.references.write_to(record, "");
+    }
+}
+
+/// Reads `{prefix}LAT` and `{prefix}LON`; `prefix` is empty or `MY_`.
+/// A valid field without the other one is noted and preserved as is,
+/// and `{prefix}GRIDSQUARE` is warned if the grid does not contain the location.
+pub(crate) fn read_location(
+    reader: &mut FieldReader,
+    prefix: &str,
+    grid: Option<GridLocator>,
+) -> Option<Location> {
+    let lat_field = format!("{prefix}LAT");
+    let lon_field = format!("{prefix}LON");
+    let latitude = reader.optional(&lat_field, |s| Ok(parse_adif_latitude(s)?));
+    let longitude = reader.optional(&lon_field, |s| Ok(parse_adif_longitude(s)?));
+    let location = match (latitude, longitude) {
+        (Some(latitude), Some(longitude)) => Location::from_lnglat(longitude, latitude),
+        _ => {
+            for (field, parsed) in [(&lat_field, latitude), (&lon_field, longitude)] {
+                if parsed.is_some() {
+                    reader.note(field, QsoError::IncompleteLocation);
+                    reader.preserve(field);
+                }
+            }
+            return None;
+        }
+    };
+    let location = match location {
+        Ok(location) => location,
+        Err(e) => {
+            reader.warn(&lat_field, e.clone().into());
+            reader.warn(&lon_field, e.into());
+            reader.preserve(&lat_field);
+            reader.preserve(&lon_field);
+            return None;
+        }
+    };
+
+    if let Some(grid) = grid
+        && !grid.contains_lnglat(location.lnglat())
+    {
+        reader.warn(&format!("{prefix}GRIDSQUARE"), QsoError::LocationMismatch);
+    }
+    Some(location)
+}
+
+/// Writes `{prefix}LAT` and `{prefix}LON`; `prefix` is empty or `MY_`.
+pub(crate) fn write_location(record: &mut Record, prefix: &str, location: Option<Location>) {
+    set_optional_field(
+        record,
+        &format!("{prefix}LAT"),
+        location.map(|l| l.adif_latitude()),
+    );
+    set_optional_field(
+        record,
+        &format!("{prefix}LON"),
+        location.map(|l| l.adif_longitude()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use adif_reader::{LengthMode, read_adi};
+
+    use super::{ContactedStation, Continent};
+    use crate::error::QsoError;
+
+    #[test]
+    fn parses_station() {
+        let adif = read_adi(
+            "<NAME:4>Taro<NAME_INTL:2>太郎<GRIDSQUARE:6>PM95vq<VUCC_GRIDS:9>PM95,PM96<DXCC:3>339<CQZ:2>25<ITUZ:2>45<CONT:2>AS<IOTA:6>AS-007<POTA_REF:15>JA-0001,JA-0002<eor>",
+            LengthMode::Codepoints,
+        )
+        .expect("valid ADI");
+        let station = ContactedStation::new(&adif.records()[0]).expect("valid station");
+
+        assert_eq!(station.name.as_deref(), Some("太郎"));
+        assert_eq!(
+            station.grid.map(|g| g.to_string()).as_deref(),
+            Some("PM95vq")
+        );
+        assert_eq!(station.vucc_grids.len(), 2);
+        assert_eq!(station.dxcc, Some(339));
+        assert_eq!(station.cq_zone, Some(25));
+        assert_eq!(station.itu_zone, Some(45));
+        assert_eq!(station.continent, Some(Continent::Asia));
+        assert_eq!(
+            station.references.iota.map(|r| r.to_string()).as_deref(),
+            Some("AS-007")
+        );
+        assert_eq!(station.references.pota.len(), 2);
+    }
+
+    #[test]
+    fn drops_stale_ascii_name() {
+        let mut adif = read_adi("<NAME:4>Taro<NAME_INTL:2>太郎<eor>", LengthMode::Codepoints)
+            .expect("valid ADI");
+        let record = &mut adif.records_mut()[0];
+        let mut station = ContactedStation::new(record).expect("valid station");
+        station.write_to(record);
+        assert_eq!(record.field("NAME"), Some("Taro"));
+
+        station.name = Some("花子".into());
+        station.write_to(record);
+        assert_eq!(record.field("NAME"), None);
+        assert_eq!(record.field("NAME_INTL"), Some("花子"));
+    }
+
+    #[test]
+    fn rejects_zone_out_of_range() {
+        let adif = read_adi("<CQZ:2>41<eor>", LengthMode::Bytes).expect("valid ADI");
+        assert!(matches!(
+            ContactedStation::new(&adif.records()[0]),
+            Err(QsoError::OutOfRange("CQZ"))
+        ));
+    }
+
+    #[test]
+    fn rejects_half_location() {
+        let adif = read_adi("<LAT:11>N035 40.000<eor>", LengthMode::Bytes).expect("valid ADI");
+        assert!(matches!(
+            ContactedStation::new(&adif.records()[0]),
+            Err(QsoError::IncompleteLocation)
+        ));
+    }
+}
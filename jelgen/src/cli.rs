@@ -2,6 +2,7 @@ use std::{path::PathBuf, str::FromStr};
 
 use adif_reader::LengthMode;
 use clap::{Parser, ValueEnum};
+use common_qso::bandplan::LicenseClass;
 use time::{
     UtcOffset, error::Parse as TimeParseError, format_description::BorrowedFormatItem,
     macros::format_description,
@@ -25,10 +26,32 @@ pub struct Arguments {
     #[clap(short, long = "lenient")]
     pub lenient_length: Option<LenientMode>,
 
+    /// Skip records which have any malformed fields.
+    /// By default, only records with missing or malformed required fields are skipped.
+    #[clap(short, long)]
+    pub strict: bool,
+
     /// Specify datetime offset of imported records.
     /// Pedantic ADI file must have datetime with UTC.
     #[clap(short = 'o', long)]
     pub import_offset: Option<ImportOffset>,
+
+    /// Check QSOs against the JA band plan and power limit of the license class (1 to 4).
+    #[clap(short = 'L', long)]
+    pub license: Option<LicenseClass>,
+
+    /// ADIF files of other participants to cross-check QSOs with.
+    /// Each file must have STATION_CALLSIGN or OPERATOR field.
+    #[clap(short = 'x', long = "cross-check")]
+    pub cross_check_files: Vec<PathBuf>,
+
+    /// Export accepted QSOs of the ADIF file in JSON Lines.
+    #[clap(short = 'j', long = "export-json")]
+    pub export_json: Option<PathBuf>,
+
+    /// Callsign database such as MASTER.SCP to check worked callsigns with.
+    #[clap(long)]
+    pub scp: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
@@ -1,11 +1,29 @@
 mod cli;
 
-use std::fs::read_to_string;
+use std::{
+    fs::{File, read_to_string},
+    io::BufWriter,
+    path::Path,
+};
 
-use adif_reader::read_adi;
+use adif_reader::{LengthMode, read_adi};
 use anyhow::Result;
+use callfind::{
+    callsign::{base_callsign, validate_callsign},
+    scp::ScpDatabase,
+};
 use clap::Parser;
-use tracing::info;
+use common_qso::{
+    bandplan::BandPlan,
+    crosscheck::{CheckStatus, CrossCheck, StationLog},
+    diagnostic::Severity,
+    export::write_json_lines,
+    matching::MatchIdentity,
+    qso::{Qso, Strictness},
+};
+use compact_str::ToCompactString;
+use time::UtcOffset;
+use tracing::{Level, error, info, span, warn};
 use tracing_subscriber::EnvFilter;
 
 use crate::cli::Arguments;
@@ -18,10 +36,110 @@ fn main() -> Result<()> {
         .init();
 
     let args = Arguments::parse();
+    let length_mode = args.lenient_length.unwrap_or_default().into();
+    let import_offset = args.import_offset.unwrap_or_default().into();
+    let strictness = if args.strict {
+        Strictness::Strict
+    } else {
+        Strictness::Lenient
+    };
 
-    let adi_text = read_to_string(args.adif_file)?;
-    let adif = read_adi(&adi_text, args.lenient_length.unwrap_or_default().into())?;
-    info!("{} records imported", adif.records().len());
+    let own_log = read_log(&args.adif_file, length_mode, import_offset, strictness)?;
+    info!("{} QSOs accepted", own_log.qsos.len());
+
+    if let Some(export_file) = &args.export_json {
+        let writer = BufWriter::new(File::create(export_file)?);
+        write_json_lines(writer, &own_log.qsos)?;
+        info!(
+            "{} QSOs exported to {}",
+            own_log.qsos.len(),
+            export_file.display()
+        );
+    }
+
+    for duplicate in MatchIdentity::default().find_duplicates(&own_log.qsos) {
+        let reasons: Vec<_> = duplicate.reasons.iter().map(|r| r.to_string()).collect();
+        warn!(
+            "QSO {} duplicates QSO {} ({})",
+            own_log.qsos[duplicate.right].record.call,
+            own_log.qsos[duplicate.left].record.call,
+            reasons.join(", ")
+        );
+    }
+
+    for qso in &own_log.qsos {
+        for issue in validate_callsign(&qso.record.call).issues {
+            warn!(
+                "QSO with {} at {}: {issue}",
+                qso.record.call, qso.record.datetime
+            );
+        }
+    }
+
+    if let Some(scp_file) = &args.scp {
+        let database = ScpDatabase::load(scp_file)?;
+        info!("{} callsigns loaded from SCP database", database.len());
+        for qso in &own_log.qsos {
+            let base = base_callsign(&qso.record.call);
+            if database.contains(&base) {
+                continue;
+            }
+            let candidates: Vec<_> = database
+                .near(&base, 1)
+                .into_iter()
+                .map(|(c, _)| c)
+                .collect();
+            if candidates.is_empty() {
+                warn!(
+                    "QSO with {} at {}: not found in SCP database",
+                    qso.record.call, qso.record.datetime
+                );
+            } else {
+                warn!(
+                    "QSO with {} at {}: not found in SCP database (did you mean {}?)",
+                    qso.record.call,
+                    qso.record.datetime,
+                    candidates.join(", ")
+                );
+            }
+        }
+    }
+
+    if let Some(license) = args.license {
+        let band_plan = BandPlan::japan();
+        for qso in &own_log.qsos {
+            for issue in band_plan.check(&qso.record, license) {
+                warn!(
+                    "QSO with {} at {}: {issue}",
+                    qso.record.call, qso.record.datetime
+                );
+            }
+        }
+    }
+
+    if !args.cross_check_files.is_empty() {
+        let mut logs = vec![own_log];
+        for path in &args.cross_check_files {
+            logs.push(read_log(path, length_mode, import_offset, strictness)?);
+        }
+
+        let cross_check = CrossCheck::new(&logs, Default::default())?;
+        let own_results: Vec<_> = cross_check
+            .check_all()
+            .into_iter()
+            .filter(|r| r.location.log == 0)
+            .collect();
+        let confirmed = own_results
+            .iter()
+            .filter(|r| matches!(r.status, CheckStatus::Confirmed { .. }))
+            .count();
+        info!("{confirmed}/{} QSOs confirmed", own_results.len());
+        for result in own_results {
+            if !matches!(result.status, CheckStatus::Confirmed { .. }) {
+                warn!("{}", serde_json::to_string(&result)?);
+            }
+        }
+    }
 
     /*
     let processor = Calculator::initialize(args.processor_file, Default::default())?;
@@ -90,6 +208,58 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Reads ADIF file into QSOs, logging diagnostics of each record.
+fn read_log(
+    path: &Path,
+    length_mode: LengthMode,
+    import_offset: UtcOffset,
+    strictness: Strictness,
+) -> Result<StationLog> {
+    let span = span!(Level::ERROR, "log", file = %path.display());
+    let _enter = span.enter();
+
+    let adi_text = read_to_string(path)?;
+    let adif = read_adi(&adi_text, length_mode)?;
+    info!("{} records imported", adif.records().len());
+
+    let station = adif
+        .records()
+        .iter()
+        .find_map(|r| r.field("STATION_CALLSIGN").or(r.field("OPERATOR")))
+        .unwrap_or_default();
+    if station.is_empty() {
+        warn!("station callsign not found");
+    }
+    let mut qsos = Vec::with_capacity(adif.records().len());
+    for (i, record) in adif.records().iter().enumerate() {
+        let span = span!(Level::ERROR, "record_process", index = i);
+        let _enter = span.enter();
+
+        match Qso::new(record, import_offset, strictness) {
+            Ok(qso) => {
+                for diagnostic in &qso.diagnostics {
+                    match diagnostic.severity {
+                        Severity::Info => info!("{diagnostic}"),
+                        _ => warn!("{diagnostic}"),
+                    }
+                }
+                qsos.push(qso);
+            }
+            Err(diagnostics) => {
+                for diagnostic in diagnostics {
+                    error!("{diagnostic}");
+                }
+                warn!("record skipped");
+            }
+        }
+    }
+
+    Ok(StationLog {
+        station: station.to_compact_string(),
+        qsos,
+    })
+}
+
 /*
 #[derive(Debug)]
 struct MainProcess {
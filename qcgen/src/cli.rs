@@ -19,6 +19,11 @@ pub struct Arguments {
     #[clap(short, long = "lenient")]
     pub lenient_length: Option<LenientMode>,
 
+    /// Skip records which have any malformed fields.
+    /// By default, only records with missing or malformed required fields are skipped.
+    #[clap(short, long)]
+    pub strict: bool,
+
     /// Specify instruments definition file.
     #[clap(short, long = "instruments")]
     pub instruments_files: Vec<PathBuf>,
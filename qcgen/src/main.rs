@@ -5,22 +5,26 @@ use std::{collections::HashMap, fs::read_to_string, io::stdout, process::exit, s
 
 use adif_reader::read_adi;
 use anyhow::Result;
-use callfind::grid_locator::GridLocator;
+use callfind::grid_locator::{GridLocator, GridPrecision};
 use clap::Parser;
 use common_qso::{
-    exchange::QsoExchanges,
-    qsl::{QslReceiveStatus, QslSendStatus, QslStatus},
-    record::QsoRecord,
+    diagnostic::Severity,
+    qsl::QslSendStatus,
+    qso::{Qso, Strictness},
 };
 use compact_str::ToCompactString;
 use mlua::prelude::*;
 use regex::Regex;
 use schope::{
-    data::qsl_card::{QslCard, QslCardEntry, QslInfo, QslInstrument, QslOperation},
+    data::{
+        exchange::Exchange,
+        qsl_card::{QslCard, QslCardEntry, QslInfo, QslInstrument, QslOperation},
+        satellite::Satellite,
+    },
     engine::{initialize_lua, lua_to_json},
 };
 use time::UtcOffset;
-use tracing::{Level, error, span, warn};
+use tracing::{Level, error, info, span, warn};
 use tracing_subscriber::EnvFilter;
 
 use crate::{
@@ -50,16 +54,46 @@ fn main() -> Result<()> {
     let adif = read_adi(&adi_text, args.lenient_length.unwrap_or_default().into())?;
     let instruments = read_items_from_tomls::<Instrument>(args.instruments_files);
     let operations = read_items_from_tomls::<Operation>(args.operations_files);
+    let strictness = if args.strict {
+        Strictness::Strict
+    } else {
+        Strictness::Lenient
+    };
 
     let mut entries = Vec::with_capacity(adif.records().len());
     for (i, record) in adif.records().iter().enumerate() {
         let span = span!(Level::ERROR, "record_process", index = i);
         let _enter = span.enter();
 
-        let qso_record = QsoRecord::new(record, UtcOffset::UTC)?;
-        let qso_exchanges = QsoExchanges::new(record);
-        let qsl_status = QslStatus::new(record)?;
-        let qso_power: Option<f64> = record.field("TX_PWR").and_then(|p| p.parse().ok());
+        let Qso {
+            record: qso_record,
+            exchanges: qso_exchanges,
+            station: contacted_station,
+            qsl: qsl_status,
+            satellite,
+            my_references,
+            my_grid,
+            my_location,
+            offset: _,
+            preserved: _,
+            diagnostics,
+        } = match Qso::new(record, UtcOffset::UTC, strictness) {
+            Ok(qso) => qso,
+            Err(diagnostics) => {
+                for diagnostic in diagnostics {
+                    error!("{diagnostic}");
+                }
+                warn!("record skipped");
+                continue;
+            }
+        };
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Severity::Info => info!("{diagnostic}"),
+                _ => warn!("{diagnostic}"),
+            }
+        }
+        let qso_power: Option<f64> = qso_record.tx_power.as_deref().and_then(|p| p.parse().ok());
 
         let mut instrument_key = args.instrument.as_deref();
         let mut operation_key = args.operation.as_deref();
@@ -81,17 +115,33 @@ fn main() -> Result<()> {
 
         let instrument = instrument_key.and_then(|k| instruments.get(k));
         let operation = operation_key.and_then(|k| operations.get(k));
-        let manager = manager.map(|s| s.to_compact_string());
+        let manager = manager
+            .map(|s| s.to_compact_string())
+            .or(qsl_status.route.clone());
+        let confirmed_by = qsl_status
+            .confirmed_by()
+            .map(|s| s.to_compact_string())
+            .collect();
         let power = qso_power
             .or(args.power)
             .or(instrument.and_then(|i| i.default_power));
-        let grid = operation.and_then(|o| o.location.grid).or(operation
-            .and_then(|o| o.location.lnglat)
-            .and_then(|(lng, lat)| GridLocator::from_lnglat(lng, lat).ok()));
-
+        let grid = operation
+            .and_then(|o| o.location.grid)
+            .or(operation
+                .and_then(|o| o.location.lnglat)
+                .and_then(|(lng, lat)| {
+                    GridLocator::from_lnglat(lng, lat, GridPrecision::Sub3).ok()
+                }))
+            .or(my_grid)
+            .or(my_location.and_then(|l| l.grid(GridPrecision::Sub3).ok()));
+
+        let satellite = satellite.map(|s| Satellite::new(s, &qso_record));
         entries.push(QslCardEntry {
+            exchange: Exchange::new(qso_exchanges, qso_record.mode_category()),
             qso: qso_record.into(),
-            exchange: qso_exchanges.into(),
+            station: contacted_station.into(),
+            satellite,
+            my_references: my_references.into(),
             info: QslInfo {
                 instrument: QslInstrument {
                     antenna: instrument.map(|i| i.antenna.to_compact_string()),
@@ -108,11 +158,10 @@ fn main() -> Result<()> {
                         qsl_status.send,
                         Some(QslSendStatus::Queued | QslSendStatus::Requested),
                     ),
-                    received: matches!(
-                        qsl_status.receive,
-                        Some(QslReceiveStatus::Confirmed | QslReceiveStatus::Verified)
-                    ),
+                    received: qsl_status.receive.is_some_and(|r| r.is_confirmed()),
+                    confirmed_by,
                     manager,
+                    message: qsl_status.message,
                 },
             },
         });
@@ -1,3 +1,6 @@
 pub mod exchange;
 pub mod qsl_card;
 pub mod record;
+pub mod reference;
+pub mod satellite;
+pub mod station;
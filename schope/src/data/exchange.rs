@@ -1,34 +1,95 @@
-use common_qso::exchange::QsoExchanges;
+use common_qso::{
+    exchange::{QsoExchange, QsoExchanges},
+    mode::ModeCategory,
+    report::{Report, ReportError},
+};
 use compact_str::CompactString;
 use mlua::prelude::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Exchange {
     pub tx_report: Option<CompactString>,
+    pub tx_report_detail: Option<ReportDetail>,
     pub tx_number: Option<CompactString>,
     pub rx_report: Option<CompactString>,
+    pub rx_report_detail: Option<ReportDetail>,
     pub rx_number: Option<CompactString>,
 }
 
+impl Exchange {
+    pub fn new(exchanges: QsoExchanges, category: ModeCategory) -> Exchange {
+        Exchange {
+            tx_report_detail: ReportDetail::new(&exchanges.sent, category),
+            rx_report_detail: ReportDetail::new(&exchanges.received, category),
+            tx_report: exchanges.sent.report,
+            tx_number: exchanges.sent.number,
+            rx_report: exchanges.received.report,
+            rx_number: exchanges.received.number,
+        }
+    }
+}
+
 impl IntoLua for Exchange {
     fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
         let table = lua.create_table()?;
         table.set("tx_report", self.tx_report.map(|s| s.to_string()))?;
+        table.set("tx_report_detail", self.tx_report_detail)?;
         table.set("tx_number", self.tx_number.map(|s| s.to_string()))?;
         table.set("rx_report", self.rx_report.map(|s| s.to_string()))?;
+        table.set("rx_report_detail", self.rx_report_detail)?;
         table.set("rx_number", self.rx_number.map(|s| s.to_string()))?;
 
         Ok(LuaValue::Table(table))
     }
 }
 
-impl From<QsoExchanges> for Exchange {
-    fn from(value: QsoExchanges) -> Self {
-        Exchange {
-            tx_report: value.sent.report,
-            tx_number: value.sent.number,
-            rx_report: value.received.report,
-            rx_number: value.received.number,
+/// Numeric components of signal report.
+/// `report` is None when the report cannot be parsed,
+/// and `error` tells why the report is not valid for the mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDetail {
+    pub raw: CompactString,
+    pub report: Option<Report>,
+    pub error: Option<String>,
+}
+
+impl ReportDetail {
+    fn new(exchange: &QsoExchange, category: ModeCategory) -> Option<ReportDetail> {
+        let raw = exchange.report.clone()?;
+        let (report, error) = match exchange.parsed_report(category)? {
+            Ok(report) => (Some(report), None),
+            Err(e @ ReportError::Mismatch { report, .. }) => (Some(report), Some(e.to_string())),
+            Err(e @ ReportError::Invalid(_)) => (None, Some(e.to_string())),
+        };
+        Some(ReportDetail { raw, report, error })
+    }
+}
+
+impl IntoLua for ReportDetail {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        let table = lua.create_table()?;
+        table.set("raw", self.raw.to_string())?;
+        if let Some(report) = self.report {
+            let kind = match report {
+                Report::Rs { .. } => "rs",
+                Report::Rst { .. } => "rst",
+                Report::Decibel { .. } => "db",
+            };
+            table.set("kind", kind)?;
+            table.set("readability", report.readability())?;
+            table.set("strength", report.strength())?;
+            table.set("tone", report.tone())?;
+            table.set("db", report.decibel())?;
+            if let Report::Rst { suffix, .. } = report {
+                table.set("suffix", suffix.map(|s| s.to_string()))?;
+            }
+            if let Report::Decibel { roger, .. } = report {
+                table.set("roger", roger)?;
+            }
         }
+        table.set("valid", self.error.is_none())?;
+        table.set("error", self.error)?;
+
+        Ok(LuaValue::Table(table))
     }
 }
@@ -2,12 +2,18 @@ use callfind::grid_locator::GridLocator;
 use compact_str::CompactString;
 use mlua::prelude::*;
 
-use crate::data::{exchange::Exchange, record::Record};
+use crate::data::{
+    exchange::Exchange, record::Record, reference::References, satellite::Satellite,
+    station::Station,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct QslCardEntry {
     pub qso: Record,
     pub exchange: Exchange,
+    pub station: Station,
+    pub satellite: Option<Satellite>,
+    pub my_references: References,
     pub info: QslInfo,
 }
 
@@ -16,6 +22,9 @@ impl IntoLua for QslCardEntry {
         let table = lua.create_table()?;
         table.set("qso", self.qso)?;
         table.set("exchange", self.exchange)?;
+        table.set("station", self.station)?;
+        table.set("satellite", self.satellite)?;
+        table.set("my_references", self.my_references)?;
         table.set("info", self.info)?;
 
         Ok(LuaValue::Table(table))
@@ -80,7 +89,9 @@ impl IntoLua for QslOperation {
 pub struct QslCard {
     pub should_send: bool,
     pub received: bool,
+    pub confirmed_by: Vec<CompactString>,
     pub manager: Option<CompactString>,
+    pub message: Option<CompactString>,
 }
 
 impl IntoLua for QslCard {
@@ -88,7 +99,12 @@ impl IntoLua for QslCard {
         let table = lua.create_table()?;
         table.set("should_send", self.should_send)?;
         table.set("received", self.received)?;
+        table.set(
+            "confirmed_by",
+            lua.create_sequence_from(self.confirmed_by.into_iter().map(|s| s.to_string()))?,
+        )?;
         table.set("manager", self.manager.map(|s| s.to_string()))?;
+        table.set("message", self.message.map(|s| s.to_string()))?;
 
         Ok(LuaValue::Table(table))
     }
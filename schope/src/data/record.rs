@@ -11,7 +11,12 @@ pub struct Record {
     pub band: CompactString,
     pub freq: CompactString,
     pub mode: CompactString,
+    pub submode: Option<CompactString>,
+    pub mode_category: CompactString,
     pub call: CompactString,
+    pub band_rx: Option<CompactString>,
+    pub freq_rx: Option<CompactString>,
+    pub propagation: Option<CompactString>,
 }
 
 impl IntoLua for Record {
@@ -25,7 +30,20 @@ impl IntoLua for Record {
         )?;
         table.set("freq_str", self.freq.to_string())?;
         table.set("mode", self.mode.to_string())?;
+        table.set("submode", self.submode.map(|s| s.to_string()))?;
+        table.set("mode_category", self.mode_category.to_string())?;
         table.set("call", self.call.to_string())?;
+        table.set("band_rx", self.band_rx.map(|s| s.to_string()))?;
+        table.set(
+            "freq_rx",
+            self.freq_rx
+                .as_deref()
+                .map(str::parse::<f64>)
+                .transpose()
+                .map_err(LuaError::external)?,
+        )?;
+        table.set("freq_rx_str", self.freq_rx.map(|s| s.to_string()))?;
+        table.set("propagation", self.propagation.map(|s| s.to_string()))?;
 
         Ok(LuaValue::Table(table))
     }
@@ -36,8 +54,13 @@ impl From<QsoRecord> for Record {
         Record {
             datetime: OffsetDateTime::from(value.datetime).into(),
             band: value.band.to_compact_string(),
+            mode_category: value.mode_category().to_compact_string(),
+            band_rx: value.band_rx.map(|b| b.to_compact_string()),
+            propagation: value.propagation.map(|p| p.to_compact_string()),
+            freq_rx: value.frequency_rx,
             freq: value.frequency,
             mode: value.mode,
+            submode: value.submode,
             call: value.call,
         }
     }
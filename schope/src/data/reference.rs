@@ -0,0 +1,79 @@
+use common_qso::reference::{ActivityReferences, IotaRef, PotaRef, SotaRef, WwffRef};
+use mlua::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct References {
+    pub pota: Vec<PotaRef>,
+    pub sota: Option<SotaRef>,
+    pub wwff: Option<WwffRef>,
+    pub iota: Option<IotaRef>,
+}
+
+impl IntoLua for References {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        let table = lua.create_table()?;
+        let pota = self
+            .pota
+            .into_iter()
+            .map(|r| {
+                let park = lua.create_table()?;
+                park.set("program", r.program.to_string())?;
+                park.set("number", r.number)?;
+                park.set("location", r.location.as_ref().map(|l| l.to_string()))?;
+                park.set("text", r.to_string())?;
+                Ok(park)
+            })
+            .collect::<LuaResult<Vec<_>>>()?;
+        table.set("pota", lua.create_sequence_from(pota)?)?;
+
+        let sota = self
+            .sota
+            .map(|r| {
+                let summit = lua.create_table()?;
+                summit.set("association", r.association.to_string())?;
+                summit.set("region", r.region.to_string())?;
+                summit.set("number", r.number)?;
+                summit.set("text", r.to_string())?;
+                LuaResult::Ok(summit)
+            })
+            .transpose()?;
+        table.set("sota", sota)?;
+
+        let wwff = self
+            .wwff
+            .map(|r| {
+                let area = lua.create_table()?;
+                area.set("program", r.program.to_string())?;
+                area.set("number", r.number)?;
+                area.set("text", r.to_string())?;
+                LuaResult::Ok(area)
+            })
+            .transpose()?;
+        table.set("wwff", wwff)?;
+
+        let iota = self
+            .iota
+            .map(|r| {
+                let island = lua.create_table()?;
+                island.set("continent", r.continent.to_string())?;
+                island.set("number", r.number)?;
+                island.set("text", r.to_string())?;
+                LuaResult::Ok(island)
+            })
+            .transpose()?;
+        table.set("iota", iota)?;
+
+        Ok(LuaValue::Table(table))
+    }
+}
+
+impl From<ActivityReferences> for References {
+    fn from(value: ActivityReferences) -> Self {
+        References {
+            pota: value.pota,
+            sota: value.sota,
+            wwff: value.wwff,
+            iota: value.iota,
+        }
+    }
+}
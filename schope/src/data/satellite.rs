@@ -0,0 +1,71 @@
+use common_qso::{
+    propagation::{SatelliteInfo, SatelliteLink},
+    record::QsoRecord,
+};
+use compact_str::{CompactString, ToCompactString};
+use mlua::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Satellite {
+    pub name: Option<CompactString>,
+    pub mode: Option<CompactString>,
+    pub uplink: Link,
+    pub downlink: Option<Link>,
+}
+
+impl IntoLua for Satellite {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        let table = lua.create_table()?;
+        table.set("name", self.name.map(|s| s.to_string()))?;
+        table.set("mode", self.mode.map(|s| s.to_string()))?;
+        table.set("uplink", self.uplink)?;
+        table.set("downlink", self.downlink)?;
+
+        Ok(LuaValue::Table(table))
+    }
+}
+
+impl Satellite {
+    /// Combines satellite info with the links of the record.
+    pub fn new(info: SatelliteInfo, record: &QsoRecord) -> Satellite {
+        Satellite {
+            name: info.name,
+            mode: info.mode,
+            uplink: record.uplink().into(),
+            downlink: record.downlink().map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub band: CompactString,
+    pub freq: Option<CompactString>,
+}
+
+impl IntoLua for Link {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        let table = lua.create_table()?;
+        table.set("band", self.band.to_string())?;
+        table.set(
+            "freq",
+            self.freq
+                .as_deref()
+                .map(str::parse::<f64>)
+                .transpose()
+                .map_err(LuaError::external)?,
+        )?;
+        table.set("freq_str", self.freq.map(|s| s.to_string()))?;
+
+        Ok(LuaValue::Table(table))
+    }
+}
+
+impl From<SatelliteLink> for Link {
+    fn from(value: SatelliteLink) -> Self {
+        Link {
+            band: value.band.to_compact_string(),
+            freq: value.frequency,
+        }
+    }
+}
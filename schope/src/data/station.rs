@@ -0,0 +1,80 @@
+use callfind::location::Location;
+use common_qso::station::ContactedStation;
+use compact_str::{CompactString, ToCompactString};
+use mlua::prelude::*;
+
+use crate::data::reference::References;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Station {
+    pub name: Option<CompactString>,
+    pub qth: Option<CompactString>,
+    pub grid: Option<CompactString>,
+    pub vucc_grids: Vec<CompactString>,
+    pub location: Option<Location>,
+    pub dxcc: Option<u16>,
+    pub country: Option<CompactString>,
+    pub cq_zone: Option<u8>,
+    pub itu_zone: Option<u8>,
+    pub continent: Option<CompactString>,
+    pub state: Option<CompactString>,
+    pub county: Option<CompactString>,
+    pub references: References,
+}
+
+impl IntoLua for Station {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        let table = lua.create_table()?;
+        table.set("name", self.name.map(|s| s.to_string()))?;
+        table.set("qth", self.qth.map(|s| s.to_string()))?;
+        table.set("grid", self.grid.map(|s| s.to_string()))?;
+        table.set(
+            "vucc_grids",
+            lua.create_sequence_from(self.vucc_grids.into_iter().map(|s| s.to_string()))?,
+        )?;
+        let location = match self.location {
+            Some(l) => {
+                let location = lua.create_table()?;
+                location.set("latitude", l.latitude())?;
+                location.set("longitude", l.longitude())?;
+                Some(location)
+            }
+            None => None,
+        };
+        table.set("location", location)?;
+        table.set("dxcc", self.dxcc)?;
+        table.set("country", self.country.map(|s| s.to_string()))?;
+        table.set("cq_zone", self.cq_zone)?;
+        table.set("itu_zone", self.itu_zone)?;
+        table.set("continent", self.continent.map(|s| s.to_string()))?;
+        table.set("state", self.state.map(|s| s.to_string()))?;
+        table.set("county", self.county.map(|s| s.to_string()))?;
+        table.set("references", self.references)?;
+
+        Ok(LuaValue::Table(table))
+    }
+}
+
+impl From<ContactedStation> for Station {
+    fn from(value: ContactedStation) -> Self {
+        Station {
+            name: value.name,
+            qth: value.qth,
+            grid: value.grid.map(|g| g.to_compact_string()),
+            vucc_grids: value
+                .vucc_grids
+                .into_iter()
+                .map(|g| g.to_compact_string())
+                .collect(),
+            location: value.location,
+            dxcc: value.dxcc,
+            country: value.country,
+            cq_zone: value.cq_zone,
+            itu_zone: value.itu_zone,
+            continent: value.continent.map(|c| c.to_compact_string()),
+            state: value.state,
+            county: value.county,
+            references: value.references.into(),
+        }
+    }
+}
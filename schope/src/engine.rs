@@ -8,7 +8,10 @@ use tracing::debug;
 
 use crate::{
     engine::tableop::{ensure_list, ensure_map},
-    library::{SchopeModule, datetime::DateTimeModule, jarl::JarlModule},
+    library::{
+        SchopeModule, callsign::CallsignModule, datetime::DateTimeModule, exchange::ExchangeModule,
+        jarl::JarlModule,
+    },
 };
 
 pub use serde::lua_to_json;
@@ -65,10 +68,18 @@ fn register_provided_features(lua: &Lua) -> Result<(), LuaError> {
 
     let package: LuaTable = globals.get("package")?;
     let package_preload: LuaTable = package.get("preload")?;
+    package_preload.set(
+        "callsign",
+        lua.create_function(CallsignModule::create_module_table)?,
+    )?;
     package_preload.set(
         "datetime",
         lua.create_function(DateTimeModule::create_module_table)?,
     )?;
+    package_preload.set(
+        "exchange",
+        lua.create_function(ExchangeModule::create_module_table)?,
+    )?;
     package_preload.set(
         "jarl",
         lua.create_function(JarlModule::create_module_table)?,
@@ -1,4 +1,6 @@
+pub mod callsign;
 pub mod datetime;
+pub mod exchange;
 pub mod jarl;
 
 use mlua::prelude::*;
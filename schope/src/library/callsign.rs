@@ -0,0 +1,68 @@
+use callfind::{
+    callsign::{base_callsign, wpx_prefix},
+    scp::ScpDatabase,
+};
+use mlua::prelude::*;
+
+use crate::library::SchopeModule;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SchopeScpDatabase(ScpDatabase);
+
+impl SchopeScpDatabase {
+    fn near(
+        &self,
+        lua: &Lua,
+        (call, max_distance): (String, Option<usize>),
+    ) -> Result<LuaTable, LuaError> {
+        let table = lua.create_table()?;
+        for (found, distance) in self.0.near(&call, max_distance.unwrap_or(1)) {
+            let entry = lua.create_table()?;
+            entry.set("call", found)?;
+            entry.set("distance", distance)?;
+            table.push(entry)?;
+        }
+        Ok(table)
+    }
+}
+
+impl LuaUserData for SchopeScpDatabase {
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("contains", |_, this, c: String| Ok(this.0.contains(&c)));
+        methods.add_method("partial", |lua, this, p: String| {
+            lua.create_sequence_from(this.0.partial(&p))
+        });
+        methods.add_method("near", |lua, this, args| this.near(lua, args));
+        methods.add_meta_method(LuaMetaMethod::Len, |_, this, ()| Ok(this.0.len()));
+    }
+}
+
+#[derive(Debug)]
+pub struct CallsignModule;
+
+impl CallsignModule {
+    fn load_scp(path: String) -> Result<SchopeScpDatabase, LuaError> {
+        ScpDatabase::load(path)
+            .map(SchopeScpDatabase)
+            .map_err(LuaError::external)
+    }
+}
+
+impl SchopeModule for CallsignModule {
+    fn create_module_table(lua: &Lua, _: LuaMultiValue) -> Result<LuaTable, LuaError> {
+        let t = lua.create_table()?;
+
+        t.set(
+            "base",
+            lua.create_function(|_, c: String| Ok(base_callsign(&c)))?,
+        )?;
+        t.set(
+            "wpx_prefix",
+            lua.create_function(|_, c: String| Ok(wpx_prefix(&c)))?,
+        )?;
+        t.set("load_scp", lua.create_function(|_, p| Self::load_scp(p))?)?;
+
+        Ok(t)
+    }
+}
@@ -0,0 +1,79 @@
+use common_qso::exchange::{ExchangeComponent, ExchangeTemplate, JarlAreaKind, ParsedExchange};
+use mlua::prelude::*;
+
+use crate::library::SchopeModule;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SchopeExchangeTemplate(ExchangeTemplate);
+
+impl SchopeExchangeTemplate {
+    fn parse(
+        &self,
+        lua: &Lua,
+        text: String,
+    ) -> Result<(Option<LuaTable>, Option<String>), LuaError> {
+        match self.0.parse(&text) {
+            Ok(parsed) => Ok((Some(parsed_to_table(lua, &parsed)?), None)),
+            Err(e) => Ok((None, Some(e.to_string()))),
+        }
+    }
+}
+
+impl LuaUserData for SchopeExchangeTemplate {
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("parse", |lua, this, t| this.parse(lua, t));
+        methods.add_meta_method(LuaMetaMethod::ToString, |_, this, ()| {
+            Ok(this.0.to_string())
+        });
+    }
+}
+
+fn parsed_to_table(lua: &Lua, parsed: &ParsedExchange) -> Result<LuaTable, LuaError> {
+    let table = lua.create_table()?;
+    for component in parsed.components() {
+        let key = component.part().to_string();
+        match component {
+            ExchangeComponent::Report(r) => table.set(key, r.to_string())?,
+            ExchangeComponent::Serial(s) => table.set(key, *s)?,
+            ExchangeComponent::JarlArea(a) => {
+                let area = lua.create_table()?;
+                let kind = match a.kind {
+                    JarlAreaKind::Prefecture => "prefecture",
+                    JarlAreaKind::Subprefecture => "subprefecture",
+                    JarlAreaKind::City => "city",
+                    JarlAreaKind::Gun => "gun",
+                    JarlAreaKind::Ward => "ward",
+                };
+                area.set("kind", kind)?;
+                area.set("code", a.code.as_str())?;
+                area.set("prefecture", a.prefecture())?;
+                table.set(key, area)?;
+            }
+            ExchangeComponent::Power(p) => table.set(key, p.to_string())?,
+            ExchangeComponent::CqZone(z) | ExchangeComponent::ItuZone(z) => table.set(key, *z)?,
+            ExchangeComponent::Text(t) => table.set(key, t.as_str())?,
+        }
+    }
+    Ok(table)
+}
+
+pub struct ExchangeModule;
+
+impl ExchangeModule {
+    fn template(spec: String) -> Result<SchopeExchangeTemplate, LuaError> {
+        spec.parse()
+            .map(SchopeExchangeTemplate)
+            .map_err(LuaError::external)
+    }
+}
+
+impl SchopeModule for ExchangeModule {
+    fn create_module_table(lua: &Lua, _: LuaMultiValue) -> Result<LuaTable, LuaError> {
+        let t = lua.create_table()?;
+
+        t.set("template", lua.create_function(|_, s| Self::template(s))?)?;
+
+        Ok(t)
+    }
+}